@@ -1,10 +1,20 @@
 use crate::*;
 use near_sdk::{ext_contract};
-use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, bytes_for_approved_account_id, refund_approved_account_ids, refund_approved_account_ids_iter, refund_deposit};
+use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, bytes_for_approved_account_id, hash_account_id, refund_approved_account_ids, refund_approved_account_ids_iter, refund_deposit, refund_released_storage};
 
-pub trait NonFungibleTokenCore {
-    //approve an account ID to transfer a token on your behalf
-    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>);
+// 토큰은 조회할 때마다 승인 목록 전체를 역직렬화하므로, 전송과 nft_resolve_transfer 가
+// 가스 부족으로 실패하지 않도록 승인 수를 제한합니다.
+pub const DEFAULT_MAX_APPROVALS_PER_TOKEN: u32 = 32;
+
+pub trait NonFungibleTokenApproval {
+    //approve an account ID to transfer a token on your behalf, optionally until expires_at (block timestamp in nanoseconds)
+    fn nft_approve(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        msg: Option<String>,
+        expires_at: Option<U64>,
+    );
 
     //check if the passed in account has access to approve the token ID
     fn nft_is_approved(
@@ -19,6 +29,9 @@ pub trait NonFungibleTokenCore {
 
     //revoke all accounts from transferring the token on your behalf
     fn nft_revoke_all(&mut self, token_id: TokenId);
+
+    //remove every expired approval of the token and refund the storage to the owner. Anyone can call this
+    fn prune_expired_approvals(&mut self, token_id: TokenId) -> u32;
 }
 
 #[ext_contract(ext_non_fungible_approval_receiver)]
@@ -34,11 +47,17 @@ trait NonFungibleTokenApprovalsReceiver {
 }
 
 #[near_bindgen]
-impl NonFungibleTokenCore for Contract {
+impl NonFungibleTokenApproval for Contract {
 
     //allow a specific account ID to approve a token on your behalf
     #[payable]
-    fn nft_approve(&mut self, token_id: TokenId, account_id: AccountId, msg: Option<String>) {
+    fn nft_approve(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        msg: Option<String>,
+        expires_at: Option<U64>,
+    ) {
         assert_at_least_one_yocto();
         let mut token = self.tokens_by_id.get(&token_id).expect("No token");
 
@@ -47,6 +66,18 @@ impl NonFungibleTokenCore for Contract {
             &token.owner_id,
             "Predecessor must be the token owner."
         );
+        self.assert_no_transfer_offer(&token_id);
+        self.assert_not_frozen(&token_id);
+        self.assert_not_blocked(&token.owner_id);
+        self.assert_not_blocked(&account_id);
+        if !token.approved_account_ids.contains_key(&account_id) {
+            assert!(
+                (token.approved_account_ids.len() as u32) < self.max_approvals_per_token,
+                "Token {} already has the maximum of {} approvals. Revoke an approval or prune expired approvals first",
+                token_id,
+                self.max_approvals_per_token,
+            );
+        }
         let approval_id: u64 = token.next_approval_id;
 
         let is_new_approval = token
@@ -56,12 +87,35 @@ impl NonFungibleTokenCore for Contract {
             //if the key was not present, .is_none() will return true so it is a new approval.
             .is_none();
 
-        let storage_used = if is_new_approval {
+        let mut storage_used = if is_new_approval {
             bytes_for_approved_account_id(&account_id)
             //if it was not a new approval, we used no storage.
         } else {
             0
         };
+
+        match expires_at {
+            Some(expires_at) => {
+                assert!(
+                    expires_at.0 > env::block_timestamp(),
+                    "expires_at must be in the future"
+                );
+                //a new expiry entry takes as much storage as a new approval
+                if token
+                    .approval_expires_at
+                    .insert(account_id.clone(), expires_at.0)
+                    .is_none()
+                {
+                    storage_used += bytes_for_approved_account_id(&account_id);
+                }
+            }
+            None => {
+                //re-approving without expires_at makes the approval permanent, so the expiry entry is released
+                if token.approval_expires_at.remove(&account_id).is_some() {
+                    refund_approved_account_ids_iter(token.owner_id.clone(), [account_id.clone()].iter());
+                }
+            }
+        }
         token.next_approval_id += 1;
         //insert the token back into the tokens_by_id collection
         self.tokens_by_id.insert(&token_id, &token);
@@ -89,15 +143,15 @@ impl NonFungibleTokenCore for Contract {
     ) -> bool {
         let token = self.tokens_by_id.get(&token_id).expect("No token");
 
-        //get the approval number for the passed in account ID
-        let approval = token.approved_account_ids.get(&approved_account_id);
+        //get the approval number for the passed in account ID. expired approvals are treated as missing
+        let approval = token.active_approval_id(&approved_account_id);
 
         //if there was some approval ID found for the account ID
         if let Some(approval) = approval {
             //if a specific approval_id was passed into the function
             if let Some(approval_id) = approval_id {
                 //return if the approval ID passed in matches the actual approval ID for the account
-                approval_id == *approval
+                approval_id == approval
                 //if there was no approval_id passed into the function, we simply return true
             } else {
                 true
@@ -126,8 +180,14 @@ impl NonFungibleTokenCore for Contract {
             .remove(&account_id)
             .is_some()
         {
+            //the expiry entry of the approval is released as well
+            let mut released = vec![account_id.clone()];
+            if token.approval_expires_at.remove(&account_id).is_some() {
+                released.push(account_id);
+            }
+
             //refund the funds released by removing the approved_account_id to the caller of the function
-            refund_approved_account_ids_iter(predecessor_account_id, [account_id].iter());
+            refund_approved_account_ids_iter(predecessor_account_id, released.iter());
 
             //insert the token back into the tokens_by_id collection with the account_id removed from the approval list
             self.tokens_by_id.insert(&token_id, &token);
@@ -148,11 +208,131 @@ impl NonFungibleTokenCore for Contract {
         //only revoke if the approved account IDs for the token is not empty
         if !token.approved_account_ids.is_empty() {
             //refund the approved account IDs to the caller of the function
-            refund_approved_account_ids(predecessor_account_id, &token.approved_account_ids);
+            refund_approved_account_ids(
+                predecessor_account_id,
+                &token.approved_account_ids,
+                &token.approval_expires_at,
+            );
             //clear the approved account IDs
             token.approved_account_ids.clear();
+            token.approval_expires_at.clear();
             //insert the token back into the tokens_by_id collection with the approved account IDs cleared
             self.tokens_by_id.insert(&token_id, &token);
         }
     }
-}
\ No newline at end of file
+
+    fn prune_expired_approvals(&mut self, token_id: TokenId) -> u32 {
+        let mut token = self.tokens_by_id.get(&token_id).expect("No token");
+        let now = env::block_timestamp();
+
+        //collect the accounts whose approval has expired
+        let expired: Vec<AccountId> = token
+            .approval_expires_at
+            .iter()
+            .filter(|(_, expires_at)| **expires_at <= now)
+            .map(|(account_id, _)| account_id.clone())
+            .collect();
+
+        if expired.is_empty() {
+            return 0;
+        }
+
+        for account_id in &expired {
+            token.approved_account_ids.remove(account_id);
+            token.approval_expires_at.remove(account_id);
+        }
+        self.tokens_by_id.insert(&token_id, &token);
+
+        //both the approval and its expiry entry are released, and the storage goes back to the owner who paid for it
+        refund_approved_account_ids_iter(token.owner_id, expired.iter().chain(expired.iter()));
+
+        expired.len() as u32
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    //allow an operator to transfer every token of the caller, including tokens received later
+    #[payable]
+    pub fn nft_approve_all(&mut self, operator_id: AccountId) {
+        assert_at_least_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        assert_ne!(owner_id, operator_id, "Owner cannot be its own operator");
+        self.assert_not_blocked(&owner_id);
+        self.assert_not_blocked(&operator_id);
+
+        let initial_storage_usage = env::storage_usage();
+
+        let mut operators = self.operators_per_owner.get(&owner_id).unwrap_or_else(|| {
+            UnorderedSet::new(
+                StorageKey::OperatorsPerOwnerInner {
+                    account_id_hash: hash_account_id(&owner_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        operators.insert(&operator_id);
+        self.operators_per_owner.insert(&owner_id, &operators);
+
+        //charge the caller for the storage used by the new operator
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+    }
+
+    //revoke a single operator of the caller
+    #[payable]
+    pub fn nft_revoke_operator(&mut self, operator_id: AccountId) {
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
+
+        if let Some(mut operators) = self.operators_per_owner.get(&owner_id) {
+            if operators.remove(&operator_id) {
+                if operators.is_empty() {
+                    self.operators_per_owner.remove(&owner_id);
+                } else {
+                    self.operators_per_owner.insert(&owner_id, &operators);
+                }
+                refund_released_storage(owner_id, initial_storage_usage);
+            }
+        }
+    }
+
+    //revoke every operator of the caller
+    #[payable]
+    pub fn nft_revoke_all_operators(&mut self) {
+        assert_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
+
+        if let Some(mut operators) = self.operators_per_owner.remove(&owner_id) {
+            operators.clear();
+            refund_released_storage(owner_id, initial_storage_usage);
+        }
+    }
+
+    //check if the operator can transfer every token of the owner
+    pub fn nft_is_approved_for_all(&self, owner_id: AccountId, operator_id: AccountId) -> bool {
+        self.internal_is_operator(&owner_id, &operator_id)
+    }
+
+    //number of approvals currently stored on the token, including expired ones that were not pruned yet
+    pub fn nft_approval_count(&self, token_id: TokenId) -> u32 {
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        token.approved_account_ids.len() as u32
+    }
+
+    pub fn get_max_approvals_per_token(&self) -> u32 {
+        self.max_approvals_per_token
+    }
+
+    //change the maximum number of approvals per token. only the contract owner can call this
+    #[payable]
+    pub fn set_max_approvals_per_token(&mut self, max_approvals_per_token: u32) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(max_approvals_per_token > 0, "max_approvals_per_token must be positive");
+
+        self.max_approvals_per_token = max_approvals_per_token;
+    }
+}
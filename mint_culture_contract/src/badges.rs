@@ -0,0 +1,109 @@
+use crate::*;
+use crate::internal::refund_deposit;
+
+// 배지 토큰 ID 의 접두사. 다른 민팅 경로에서는 이 접두사로 시작하는 토큰 ID 를 쓸 수 없음
+pub const BADGE_TOKEN_PREFIX: &str = "badge-";
+
+// 한 계정이 같은 스트리머의 토큰을 threshold 개 이상 보유하게 되면 자동으로 발급되는 업적 배지
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AchievementBadge {
+    pub threshold: u32,
+    // 배지 토큰의 메타데이터. streamer_name 은 달성한 스트리머 이름으로 바뀜
+    pub metadata: TokenMetadata,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 업적 배지 설정 (소유자만 가능). 이미 발급된 배지에는 영향 없음
+    #[payable]
+    pub fn set_achievement_badges(&mut self, badges: Vec<AchievementBadge>) {
+        self.assert_owner();
+        let mut previous_threshold = 0;
+        for badge in &badges {
+            assert!(
+                badge.threshold > previous_threshold,
+                "Badge thresholds must be positive and in increasing order"
+            );
+            previous_threshold = badge.threshold;
+            badge.metadata.assert_valid(&self.metadata_limits);
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        self.achievement_badges = badges;
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+    }
+
+    pub fn get_achievement_badges(&self) -> Vec<AchievementBadge> {
+        self.achievement_badges.clone()
+    }
+
+    // 계정이 스트리머에 대해 받은 배지 토큰 ID
+    pub fn get_awarded_badge(&self, account_id: AccountId, streamer_name: String, threshold: u32) -> Option<TokenId> {
+        self.awarded_badges.get(&(account_id, streamer_name, threshold))
+    }
+
+    pub fn is_soulbound(&self, token_id: TokenId) -> bool {
+        self.badge_tokens.contains(&token_id)
+    }
+}
+
+impl Contract {
+    // 토큰을 받은 계정이 새로 도달한 배지를 발급합니다. 계정마다 스트리머와 기준별로 한 번만 발급됩니다.
+    // 배지 민팅 스토리지는 민팅에서는 첨부한 보증금으로, NEP-171 전송에서는 전송을 시작한 계정의
+    // 스토리지 잔액(storage_deposit)으로 냅니다.
+    pub(crate) fn internal_award_badges(&mut self, account_id: &AccountId, token_id: &TokenId) {
+        if self.achievement_badges.is_empty() || self.badge_tokens.contains(token_id) {
+            return;
+        }
+        let streamer_name = match self.token_metadata_by_id.get(token_id) {
+            Some(metadata) => metadata.streamer_name,
+            None => return,
+        };
+        let held = self.internal_held_per_streamer(account_id, &streamer_name);
+
+        for badge in self.achievement_badges.clone() {
+            if held < badge.threshold {
+                break;
+            }
+            let key = (account_id.clone(), streamer_name.clone(), badge.threshold);
+            if self.awarded_badges.contains_key(&key) {
+                continue;
+            }
+
+            let badge_token_id = format!("{}{}", BADGE_TOKEN_PREFIX, self.next_mint_seq);
+            self.awarded_badges.insert(&key, &badge_token_id);
+            // internal_mint 보다 먼저 표시해야 제한 확인과 보유 수 집계에서 빠짐
+            self.badge_tokens.insert(&badge_token_id);
+
+            let mut metadata = badge.metadata;
+            metadata.streamer_name = streamer_name.clone();
+            self.internal_mint(badge_token_id.clone(), metadata, account_id.clone(), None);
+
+            EventLog::mint_culture(EventLogVariant::BadgeAward(vec![BadgeAwardLog {
+                owner_id: account_id.clone(),
+                token_id: badge_token_id,
+                streamer_name: streamer_name.clone(),
+                threshold: badge.threshold,
+            }]))
+            .emit();
+        }
+    }
+
+    // 배지 발급이 아닌 민팅에서 배지 토큰 ID 를 선점하지 못하게 막음
+    pub(crate) fn assert_not_badge_token_id(&self, token_id: &str) {
+        assert!(
+            !token_id.starts_with(BADGE_TOKEN_PREFIX) || self.badge_tokens.contains(&token_id.to_string()),
+            "Token IDs starting with {} are reserved for achievement badges",
+            BADGE_TOKEN_PREFIX
+        );
+    }
+
+    pub(crate) fn assert_not_soulbound(&self, token_id: &TokenId) {
+        assert!(
+            !self.badge_tokens.contains(token_id),
+            "Token {} is a soulbound badge and cannot be transferred",
+            token_id
+        );
+    }
+}
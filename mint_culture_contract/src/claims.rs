@@ -0,0 +1,157 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, refund_deposit};
+use crate::vouchers::verify_ed25519;
+use near_sdk::CurveType;
+
+pub type ClaimTemplateId = String;
+
+// 오프라인 행사에서 나눠주는 1회용 클레임 코드. 코드는 ed25519 비밀 키이고 컨트랙트에는 공개 키만 저장함
+//
+// 클레임할 때는 비밀 키로 borsh((컨트랙트 계정 ID, 받는 계정 ID)) 에 서명해서 제출하므로
+// 대기 중인 트랜잭션을 보고 같은 코드로 다른 계정에 먼저 클레임할 수 없음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ClaimCode {
+    pub template_id: ClaimTemplateId,
+    pub expires_at: Option<U64>,
+    // 클레임 시 민팅 스토리지에 쓰도록 소유자가 미리 낸 보증금
+    pub storage_deposit: U128,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 클레임 코드로 민팅될 토큰의 메타데이터 템플릿 등록 (소유자만 가능)
+    #[payable]
+    pub fn add_claim_template(&mut self, template_id: ClaimTemplateId, metadata: TokenMetadata) {
+        self.assert_owner();
+        metadata.assert_valid(&self.metadata_limits);
+        // 클레임으로 민팅할 토큰 ID 가 공개 시리즈 토큰 ID 와 겹치지 않아야 함
+        self.assert_not_reveal_token_id(&format!("{}-", template_id));
+
+        let initial_storage_usage = env::storage_usage();
+        assert!(
+            self.claim_templates.insert(&template_id, &metadata).is_none(),
+            "Claim template {} already exists",
+            template_id
+        );
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+    }
+
+    pub fn get_claim_template(&self, template_id: ClaimTemplateId) -> Option<TokenMetadata> {
+        self.claim_templates.get(&template_id)
+    }
+
+    // 클레임 코드 공개 키 등록 (소유자만 가능). 코드 저장 비용과 코드마다 storage_deposit_per_code 를 첨부해야 함
+    #[payable]
+    pub fn add_claim_codes(
+        &mut self,
+        template_id: ClaimTemplateId,
+        public_keys: Vec<PublicKey>,
+        expires_at: Option<U64>,
+        storage_deposit_per_code: U128,
+    ) {
+        self.assert_owner();
+        assert!(self.claim_templates.get(&template_id).is_some(), "Claim template {} does not exist", template_id);
+
+        let initial_storage_usage = env::storage_usage();
+        let claim_code = ClaimCode {
+            template_id,
+            expires_at,
+            storage_deposit: storage_deposit_per_code,
+        };
+        for public_key in &public_keys {
+            assert!(
+                matches!(public_key.curve_type(), CurveType::ED25519),
+                "Claim codes must be ed25519 keys"
+            );
+            assert!(
+                self.claim_codes.insert(&public_key.as_bytes().to_vec(), &claim_code).is_none(),
+                "Claim code is already registered"
+            );
+        }
+
+        let storage_cost = env::storage_byte_cost() * Balance::from(env::storage_usage() - initial_storage_usage);
+        let required_deposit = storage_cost + storage_deposit_per_code.0 * public_keys.len() as Balance;
+        let attached_deposit = env::attached_deposit();
+        assert!(
+            required_deposit <= attached_deposit,
+            "Must attach {} yoctoNEAR to cover the claim codes and their storage deposits",
+            required_deposit
+        );
+        let refund = attached_deposit - required_deposit;
+        if refund > 1 {
+            Promise::new(env::predecessor_account_id()).transfer(refund);
+        }
+    }
+
+    pub fn get_claim_code(&self, public_key: PublicKey) -> Option<ClaimCode> {
+        self.claim_codes.get(&public_key.as_bytes().to_vec())
+    }
+
+    // 클레임 코드로 토큰을 받습니다. 누구나 호출할 수 있지만 서명에 담긴 receiver_id 로만 받을 수 있고
+    // 코드는 한 번만 사용할 수 있습니다.
+    pub fn claim(&mut self, public_key: PublicKey, receiver_id: AccountId, signature: Base64VecU8) -> TokenId {
+        let key = public_key.as_bytes().to_vec();
+        let claim_code = self.claim_codes.get(&key).expect("Invalid or already used claim code");
+        assert!(
+            verify_ed25519(&public_key, &claim_signing_message(&receiver_id), &signature.0),
+            "Invalid claim signature"
+        );
+        if let Some(expires_at) = claim_code.expires_at {
+            assert!(env::block_timestamp() < expires_at.0, "The claim code has expired");
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        self.claim_codes.remove(&key);
+
+        let metadata = self.claim_templates.get(&claim_code.template_id).expect("Claim template does not exist");
+        let token_id = format!("{}-{}", claim_code.template_id, self.next_mint_seq);
+        self.internal_mint(token_id.clone(), metadata, receiver_id, None);
+
+        // 민팅 스토리지는 소유자가 미리 낸 보증금으로 충당하고 남은 금액은 소유자에게 돌려줌
+        let storage_used = env::storage_usage().saturating_sub(initial_storage_usage);
+        let storage_cost = env::storage_byte_cost() * Balance::from(storage_used);
+        let released = env::storage_byte_cost()
+            * Balance::from(initial_storage_usage.saturating_sub(env::storage_usage()));
+        assert!(
+            storage_cost <= claim_code.storage_deposit.0,
+            "The storage deposit of the claim code does not cover minting"
+        );
+        let refund = claim_code.storage_deposit.0 - storage_cost + released;
+        if refund > 0 {
+            Promise::new(self.owner_id.clone()).transfer(refund);
+        }
+
+        token_id
+    }
+
+    // 사용되지 않은 클레임 코드를 한꺼번에 폐기하고 보증금과 스토리지 비용을 돌려받음 (소유자만 가능)
+    // 만료된 코드의 보증금도 이 방법으로 돌려받습니다.
+    #[payable]
+    pub fn revoke_claim_codes(&mut self, public_keys: Vec<PublicKey>) -> u32 {
+        assert_one_yocto();
+        self.assert_owner();
+
+        let initial_storage_usage = env::storage_usage();
+        let mut revoked = 0;
+        let mut deposits: Balance = 0;
+        for public_key in public_keys {
+            if let Some(claim_code) = self.claim_codes.remove(&public_key.as_bytes().to_vec()) {
+                deposits += claim_code.storage_deposit.0;
+                revoked += 1;
+            }
+        }
+
+        let released = env::storage_byte_cost()
+            * Balance::from(initial_storage_usage.saturating_sub(env::storage_usage()));
+        if deposits + released > 0 {
+            Promise::new(self.owner_id.clone()).transfer(deposits + released);
+        }
+        revoked
+    }
+}
+
+// 클레임 코드 비밀 키로 서명해야 하는 메시지
+pub(crate) fn claim_signing_message(receiver_id: &AccountId) -> Vec<u8> {
+    (env::current_account_id(), receiver_id).try_to_vec().unwrap()
+}
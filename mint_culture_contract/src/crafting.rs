@@ -0,0 +1,184 @@
+use std::collections::HashSet;
+
+use crate::*;
+use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, refund_deposit, refund_released_storage};
+
+pub type RecipeId = String;
+
+// 레시피의 입력 조건 하나. None 인 항목은 검사하지 않음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CraftingInput {
+    pub streamer_name: Option<String>,
+    // 미스터리 박스나 제작으로 정해진 희귀도 등급
+    pub tier: Option<String>,
+    pub count: u32,
+}
+
+// 여러 토큰을 소각하고 더 높은 등급의 토큰 하나를 만드는 레시피
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct CraftingRecipe {
+    // 입력 토큰은 조건의 순서와 관계없이 모든 조건을 채울 수 있는 배정이 있으면 받아들임
+    pub inputs: Vec<CraftingInput>,
+    pub output: TokenMetadata,
+    pub output_tier: Option<String>,
+}
+
+impl CraftingInput {
+    fn matches(&self, metadata: &TokenMetadata, tier: Option<&String>) -> bool {
+        self.streamer_name.iter().all(|streamer_name| *streamer_name == metadata.streamer_name)
+            && self.tier.iter().all(|required| Some(required) == tier)
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // 제작 레시피 생성 (소유자만 가능)
+    #[payable]
+    pub fn create_crafting_recipe(&mut self, recipe_id: RecipeId, recipe: CraftingRecipe) {
+        self.assert_owner();
+        assert!(!recipe.inputs.is_empty(), "A crafting recipe needs at least one input");
+        for input in &recipe.inputs {
+            assert!(input.count > 0, "Crafting inputs must have a positive count");
+        }
+        recipe.output.assert_valid(&self.metadata_limits);
+        // 레시피로 만들 토큰 ID 가 공개 시리즈 토큰 ID 와 겹치지 않아야 함
+        self.assert_not_reveal_token_id(&format!("{}-", recipe_id));
+
+        let initial_storage_usage = env::storage_usage();
+        assert!(
+            self.crafting_recipes.insert(&recipe_id, &recipe).is_none(),
+            "Crafting recipe {} already exists",
+            recipe_id
+        );
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+    }
+
+    // 제작 레시피 삭제 (소유자만 가능)
+    #[payable]
+    pub fn remove_crafting_recipe(&mut self, recipe_id: RecipeId) {
+        assert_one_yocto();
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        self.crafting_recipes.remove(&recipe_id).expect("Crafting recipe does not exist");
+        refund_released_storage(env::predecessor_account_id(), initial_storage_usage);
+    }
+
+    pub fn get_crafting_recipe(&self, recipe_id: RecipeId) -> Option<CraftingRecipe> {
+        self.crafting_recipes.get(&recipe_id)
+    }
+
+    // 레시피의 조건에 맞는 토큰들을 소각하고 결과 토큰을 민팅합니다. 민팅된 토큰 ID를 반환합니다.
+    // 결과 토큰이 입력 토큰들보다 스토리지를 더 쓰면 그 비용을 첨부해야 합니다.
+    #[payable]
+    pub fn nft_craft(&mut self, input_token_ids: Vec<TokenId>, recipe_id: RecipeId) -> TokenId {
+        assert_at_least_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        let recipe = self.crafting_recipes.get(&recipe_id).expect("Crafting recipe does not exist");
+
+        let required: u32 = recipe.inputs.iter().map(|input| input.count).sum();
+        assert_eq!(
+            input_token_ids.len(),
+            required as usize,
+            "Recipe {} takes exactly {} input tokens",
+            recipe_id,
+            required
+        );
+
+        // 모든 입력을 먼저 검사하고 나서 소각함
+        let mut seen = HashSet::new();
+        let mut candidates = vec![];
+        for token_id in &input_token_ids {
+            assert!(seen.insert(token_id), "Token {} is listed more than once", token_id);
+            let token = self.tokens_by_id.get(token_id).unwrap_or_else(|| panic!("Token {} does not exist", token_id));
+            assert_eq!(token.owner_id, owner_id, "Token {} is not owned by {}", token_id, owner_id);
+
+            let metadata = self.token_metadata_by_id.get(token_id).expect("No token");
+            let tier = self.token_tiers.get(token_id);
+            candidates.push(recipe.inputs.iter().map(|input| input.matches(&metadata, tier.as_ref())).collect());
+        }
+        if let Some(index) = unassigned_input_token(&recipe.inputs, &candidates) {
+            panic!(
+                "Token {} does not match any remaining input of recipe {}",
+                input_token_ids[index], recipe_id
+            );
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        for token_id in &input_token_ids {
+            self.internal_burn(&owner_id, token_id);
+        }
+        EventLog::nep171(EventLogVariant::NftBurn(vec![NftBurnLog {
+            owner_id: owner_id.clone(),
+            token_ids: input_token_ids.clone(),
+            authorized_id: None,
+            memo: None,
+        }]))
+        .emit();
+
+        let token_id = format!("{}-{}", recipe_id, self.next_mint_seq);
+        self.internal_mint(token_id.clone(), recipe.output, owner_id.clone(), None);
+        if let Some(tier) = &recipe.output_tier {
+            self.token_tiers.insert(&token_id, tier);
+        }
+
+        // 소각된 입력 토큰의 스토리지는 민팅한 쪽이 냈으므로 돌려주지 않고, 늘어난 스토리지만 받음
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+
+        EventLog::mint_culture(EventLogVariant::NftCraft(vec![NftCraftLog {
+            owner_id,
+            recipe_id,
+            burned_token_ids: input_token_ids,
+            token_id: token_id.clone(),
+        }]))
+        .emit();
+
+        token_id
+    }
+}
+
+// 입력 토큰을 레시피의 입력 자리에 하나씩 배정합니다 (증가 경로를 이용한 이분 매칭).
+// candidates[토큰][조건] 은 토큰이 그 조건에 맞는지 여부. 배정할 수 없는 토큰이 있으면 그 위치를 반환합니다.
+fn unassigned_input_token(inputs: &[CraftingInput], candidates: &[Vec<bool>]) -> Option<usize> {
+    // 입력 조건을 count 개의 자리로 펼침
+    let slots: Vec<usize> = inputs
+        .iter()
+        .enumerate()
+        .flat_map(|(index, input)| std::iter::repeat_n(index, input.count as usize))
+        .collect();
+    let mut slot_tokens: Vec<Option<usize>> = vec![None; slots.len()];
+
+    for token in 0..candidates.len() {
+        let mut visited = vec![false; slots.len()];
+        if !assign_input_slot(token, candidates, &slots, &mut slot_tokens, &mut visited) {
+            return Some(token);
+        }
+    }
+    None
+}
+
+// 토큰에 빈 자리를 찾아주고, 없으면 이미 배정된 토큰을 다른 자리로 옮겨 자리를 만듦
+fn assign_input_slot(
+    token: usize,
+    candidates: &[Vec<bool>],
+    slots: &[usize],
+    slot_tokens: &mut [Option<usize>],
+    visited: &mut [bool],
+) -> bool {
+    for (slot, input) in slots.iter().enumerate() {
+        if visited[slot] || !candidates[token][*input] {
+            continue;
+        }
+        visited[slot] = true;
+        let reassigned = match slot_tokens[slot] {
+            Some(other) => assign_input_slot(other, candidates, slots, slot_tokens, visited),
+            None => true,
+        };
+        if reassigned {
+            slot_tokens[slot] = Some(token);
+            return true;
+        }
+    }
+    false
+}
@@ -0,0 +1,79 @@
+use crate::*;
+use crate::internal::assert_one_yocto;
+
+pub type DropId = String;
+
+// 같은 판매(드롭)에서 민팅된 토큰들에 공통으로 적용되는 설정
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct DropConfig {
+    // 이 시각(블록 타임스탬프, 나노초) 전에는 드롭에서 민팅된 토큰을 전송할 수 없음
+    pub transfer_unlock_at: Option<U64>,
+    // 한 계정이 이 드롭에서 받을 수 있는 최대 토큰 수
+    #[serde(default)]
+    pub max_mints_per_account: Option<u32>,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 드롭 생성 (소유자만 가능)
+    #[payable]
+    pub fn create_drop(&mut self, drop_id: DropId, config: DropConfig) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(self.drops.insert(&drop_id, &config).is_none(), "Drop {} already exists", drop_id);
+    }
+
+    // 드롭의 전송 잠금 시각 변경 (소유자만 가능)
+    #[payable]
+    pub fn set_drop_transfer_lock(&mut self, drop_id: DropId, transfer_unlock_at: U64) {
+        assert_one_yocto();
+        self.assert_owner();
+        let mut config = self.drops.get(&drop_id).expect("Drop does not exist");
+        config.transfer_unlock_at = Some(transfer_unlock_at);
+        self.drops.insert(&drop_id, &config);
+    }
+
+    // 드롭의 전송 잠금을 즉시 해제 (소유자만 가능)
+    #[payable]
+    pub fn clear_drop_transfer_lock(&mut self, drop_id: DropId) {
+        assert_one_yocto();
+        self.assert_owner();
+        let mut config = self.drops.get(&drop_id).expect("Drop does not exist");
+        config.transfer_unlock_at = None;
+        self.drops.insert(&drop_id, &config);
+    }
+
+    // 드롭의 계정별 민팅 제한 변경 (소유자만 가능). None 이면 제한 없음
+    #[payable]
+    pub fn set_drop_mint_limit(&mut self, drop_id: DropId, max_mints_per_account: Option<u32>) {
+        assert_one_yocto();
+        self.assert_owner();
+        let mut config = self.drops.get(&drop_id).expect("Drop does not exist");
+        config.max_mints_per_account = max_mints_per_account;
+        self.drops.insert(&drop_id, &config);
+    }
+
+    pub fn get_drop(&self, drop_id: DropId) -> Option<DropConfig> {
+        self.drops.get(&drop_id)
+    }
+}
+
+impl Contract {
+    // 토큰이 아직 전송 잠금 상태이면 잠금이 풀리는 시각을 반환
+    pub(crate) fn internal_transfer_locked_until(&self, token_id: &TokenId) -> Option<u64> {
+        let drop_id = self.drop_by_token.get(token_id)?;
+        let unlock_at = self.drops.get(&drop_id)?.transfer_unlock_at?.0;
+        if unlock_at > env::block_timestamp() {
+            Some(unlock_at)
+        } else {
+            None
+        }
+    }
+
+    pub(crate) fn assert_transfer_unlocked(&self, token_id: &TokenId) {
+        if let Some(unlock_at) = self.internal_transfer_locked_until(token_id) {
+            panic!("Token {} is locked for transfers until {}", token_id, unlock_at);
+        }
+    }
+}
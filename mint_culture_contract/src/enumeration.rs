@@ -1,4 +1,5 @@
 use crate::*;
+use std::ops::Bound;
 
 #[near_bindgen]
 impl Contract {
@@ -54,4 +55,129 @@ impl Contract {
             .map(|token_id| self.nft_token(token_id.clone()).unwrap())
             .collect()
     }
-}
\ No newline at end of file
+
+    // 민팅 순서대로 토큰 목록을 반환합니다. cursor 로 받은 토큰 ID 다음부터 이어서 조회하므로
+    // 중간에 토큰이 이동하거나 삭제되어도 페이지가 건너뛰거나 중복되지 않습니다.
+    pub fn nft_tokens_page(&self, cursor: Option<TokenId>, limit: Option<u64>) -> JsonTokenPage {
+        self.internal_tokens_page(&self.tokens_by_mint_seq, cursor, limit, false)
+    }
+
+    // 최신 민팅 순서(역순)로 토큰 목록을 반환합니다. cursor 로 받은 토큰 ID 이전 토큰부터 이어서 조회합니다.
+    pub fn nft_tokens_page_rev(&self, cursor: Option<TokenId>, limit: Option<u64>) -> JsonTokenPage {
+        self.internal_tokens_page(&self.tokens_by_mint_seq, cursor, limit, true)
+    }
+
+    // 가장 최근에 민팅된 토큰 목록 (최신순)
+    pub fn nft_latest_tokens(&self, limit: Option<u64>) -> Vec<JsonToken> {
+        self.nft_tokens_page_rev(None, limit).tokens
+    }
+
+    // from_ts 이상 to_ts 이하(나노초)의 블록 시각에 민팅된 토큰 목록을 민팅 순서대로 반환합니다.
+    pub fn nft_tokens_minted_between(
+        &self,
+        from_ts: U64,
+        to_ts: U64,
+        cursor: Option<TokenId>,
+        limit: Option<u64>,
+    ) -> JsonTokenPage {
+        let (from_ts, to_ts) = (u64::from(from_ts), u64::from(to_ts));
+        assert!(from_ts <= to_ts, "from_ts must not be greater than to_ts");
+
+        // 민팅 시각은 순번과 같이 단조 증가하므로 시각 구간은 순번 구간으로 바뀝니다.
+        let start_seq = match self.first_mint_seq_by_timestamp.ceil_key(&from_ts) {
+            Some(ts) => self.first_mint_seq_by_timestamp.get(&ts).unwrap(),
+            None => self.next_mint_seq,
+        };
+        let end_seq = match self.first_mint_seq_by_timestamp.higher(&to_ts) {
+            Some(ts) => self.first_mint_seq_by_timestamp.get(&ts).unwrap(),
+            None => self.next_mint_seq,
+        };
+
+        let start_seq = match cursor {
+            Some(cursor) => {
+                let cursor_seq = self.mint_seq_by_id.get(&cursor).expect("Unknown cursor token");
+                start_seq.max(cursor_seq + 1)
+            }
+            None => start_seq,
+        };
+
+        if start_seq >= end_seq {
+            return JsonTokenPage {
+                tokens: vec![],
+                next_cursor: None,
+            };
+        }
+
+        self.internal_collect_page(
+            self.tokens_by_mint_seq
+                .range((Bound::Included(start_seq), Bound::Excluded(end_seq))),
+            limit,
+        )
+    }
+
+    // 소유자가 가진 토큰 목록을 민팅 순서대로 반환합니다.
+    pub fn nft_tokens_for_owner_page(
+        &self,
+        account_id: AccountId,
+        cursor: Option<TokenId>,
+        limit: Option<u64>,
+    ) -> JsonTokenPage {
+        if let Some(tokens_by_seq) = self.tokens_per_owner_by_mint_seq.get(&account_id) {
+            self.internal_tokens_page(&tokens_by_seq, cursor, limit, false)
+        } else {
+            JsonTokenPage {
+                tokens: vec![],
+                next_cursor: None,
+            }
+        }
+    }
+}
+
+impl Contract {
+    fn internal_tokens_page(
+        &self,
+        tokens_by_seq: &TreeMap<u64, TokenId>,
+        cursor: Option<TokenId>,
+        limit: Option<u64>,
+        descending: bool,
+    ) -> JsonTokenPage {
+        // 커서 토큰의 민팅 순번 다음(역순이면 이전)부터 조회
+        let cursor_seq = cursor.map(|cursor| {
+            self.mint_seq_by_id.get(&cursor).expect("Unknown cursor token")
+        });
+        let iter: Box<dyn Iterator<Item = (u64, TokenId)>> = match (cursor_seq, descending) {
+            (Some(seq), false) => Box::new(tokens_by_seq.iter_from(seq)),
+            (Some(seq), true) => Box::new(tokens_by_seq.iter_rev_from(seq)),
+            (None, false) => Box::new(tokens_by_seq.iter()),
+            (None, true) => Box::new(tokens_by_seq.iter_rev()),
+        };
+
+        self.internal_collect_page(iter, limit)
+    }
+
+    fn internal_collect_page(
+        &self,
+        iter: impl Iterator<Item = (u64, TokenId)>,
+        limit: Option<u64>,
+    ) -> JsonTokenPage {
+        let limit = limit.unwrap_or(50) as usize;
+
+        // 다음 페이지가 있는지 알기 위해 하나 더 가져옵니다.
+        let mut tokens: Vec<JsonToken> = iter
+            .take(limit + 1)
+            .map(|(_, token_id)| self.nft_token(token_id).unwrap())
+            .collect();
+
+        let next_cursor = if tokens.len() > limit {
+            tokens.truncate(limit);
+            tokens.last().map(|token| token.token_id.clone())
+        } else {
+            None
+        };
+
+        JsonTokenPage {
+            tokens,
+            next_cursor,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::*;
+use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, refund_approved_account_ids, refund_deposit, refund_released_storage};
+
+// 수신자의 수락을 기다리는 전송 제안. 제안 중인 토큰은 전송하거나 승인할 수 없음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TransferOffer {
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    // 이 시각(블록 타임스탬프, 나노초)부터는 수락할 수 없음
+    pub expires_at: U64,
+}
+
+impl TransferOffer {
+    fn log(&self, token_id: &TokenId) -> NftTransferOfferLog {
+        NftTransferOfferLog {
+            token_id: token_id.clone(),
+            sender_id: self.sender_id.clone(),
+            receiver_id: self.receiver_id.clone(),
+            expires_at: self.expires_at,
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // 토큰을 바로 보내지 않고 수신자가 수락할 때까지 보관합니다. 제안 저장 비용은 보내는 사람이 냅니다.
+    #[payable]
+    pub fn nft_offer_transfer(&mut self, token_id: TokenId, receiver_id: AccountId, expires_at: U64) {
+        assert_at_least_one_yocto();
+        let sender_id = env::predecessor_account_id();
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+
+        assert_eq!(sender_id, token.owner_id, "Predecessor must be the token owner.");
+        assert_ne!(sender_id, receiver_id, "토큰 소유자와 토큰 수신자는 달라야 합니다.");
+        assert!(expires_at.0 > env::block_timestamp(), "expires_at must be in the future");
+        self.assert_no_transfer_offer(&token_id);
+        self.assert_transfer_unlocked(&token_id);
+        self.assert_not_frozen(&token_id);
+        self.assert_not_soulbound(&token_id);
+        self.assert_ticket_not_redeemed(&token_id);
+        self.assert_not_blocked(&sender_id);
+        self.assert_not_blocked(&receiver_id);
+
+        let initial_storage_usage = env::storage_usage();
+
+        let offer = TransferOffer {
+            sender_id,
+            receiver_id,
+            expires_at,
+        };
+        self.transfer_offers.insert(&token_id, &offer);
+
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+
+        EventLog::mint_culture(EventLogVariant::NftTransferOffer(vec![offer.log(&token_id)])).emit();
+    }
+
+    // 수신자가 제안을 수락하면 토큰이 실제로 전송됩니다.
+    #[payable]
+    pub fn nft_accept_transfer(&mut self, token_id: TokenId) {
+        assert_at_least_one_yocto();
+        let offer = self.transfer_offers.get(&token_id).expect("No transfer offer for the token");
+
+        assert_eq!(
+            env::predecessor_account_id(),
+            offer.receiver_id,
+            "Only the receiver of the offer can accept it"
+        );
+        assert!(
+            env::block_timestamp() < offer.expires_at.0,
+            "The transfer offer has expired"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+        self.transfer_offers.remove(&token_id);
+        refund_released_storage(offer.sender_id.clone(), initial_storage_usage);
+
+        // 수락하는 쪽이 소유 이력 스토리지 비용을 냄
+        let initial_storage_usage = env::storage_usage();
+        let previous_token = self.internal_transfer(
+            &offer.sender_id,
+            &offer.receiver_id,
+            &token_id,
+            None,
+            None,
+        );
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+        refund_approved_account_ids(
+            previous_token.owner_id,
+            &previous_token.approved_account_ids,
+            &previous_token.approval_expires_at,
+        );
+
+        EventLog::mint_culture(EventLogVariant::NftTransferOfferAccept(vec![offer.log(&token_id)])).emit();
+    }
+
+    // 보내는 사람이 제안을 취소하거나, 만료된 제안에서 토큰을 되찾습니다.
+    #[payable]
+    pub fn nft_cancel_transfer_offer(&mut self, token_id: TokenId) {
+        assert_one_yocto();
+        let offer = self.transfer_offers.get(&token_id).expect("No transfer offer for the token");
+
+        assert_eq!(
+            env::predecessor_account_id(),
+            offer.sender_id,
+            "Only the sender of the offer can cancel it"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+        self.transfer_offers.remove(&token_id);
+        refund_released_storage(offer.sender_id.clone(), initial_storage_usage);
+
+        EventLog::mint_culture(EventLogVariant::NftTransferOfferCancel(vec![offer.log(&token_id)])).emit();
+    }
+
+    pub fn nft_transfer_offer(&self, token_id: TokenId) -> Option<TransferOffer> {
+        self.transfer_offers.get(&token_id)
+    }
+}
+
+impl Contract {
+    // 전송 제안 중인 토큰은 다른 방법으로 전송하거나 승인할 수 없음
+    pub(crate) fn assert_no_transfer_offer(&self, token_id: &TokenId) {
+        assert!(
+            !self.transfer_offers.contains_key(token_id),
+            "Token {} has a pending transfer offer",
+            token_id
+        );
+    }
+}
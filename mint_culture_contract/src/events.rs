@@ -0,0 +1,219 @@
+use std::fmt;
+
+use crate::*;
+
+// NEP-297 이벤트 표준에 따라 로그를 남길 때 사용하는 표준 이름과 버전
+pub const NFT_STANDARD_NAME: &str = "nep171";
+pub const NFT_STANDARD_VERSION: &str = "1.1.0";
+
+// NEP-171에 정의되지 않은 Mint Culture 고유 이벤트의 표준 이름과 버전
+pub const MINT_CULTURE_STANDARD_NAME: &str = "mint_culture";
+pub const MINT_CULTURE_STANDARD_VERSION: &str = "1.0.0";
+
+// 이벤트 종류. `event` 필드에 이름이, `data` 필드에 내용이 들어감
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "event", content = "data")]
+#[serde(rename_all = "snake_case")]
+#[serde(crate = "near_sdk::serde")]
+#[non_exhaustive]
+pub enum EventLogVariant {
+    ContractMetadataUpdate(Vec<ContractMetadataUpdateLog>),
+    NftMint(Vec<NftMintLog>),
+    NftTransfer(Vec<NftTransferLog>),
+    NftBurn(Vec<NftBurnLog>),
+    NftTransferOffer(Vec<NftTransferOfferLog>),
+    NftTransferOfferAccept(Vec<NftTransferOfferLog>),
+    NftTransferOfferCancel(Vec<NftTransferOfferLog>),
+    AccountBlock(Vec<AccountBlockLog>),
+    AccountUnblock(Vec<AccountBlockLog>),
+    TokenFreeze(Vec<TokenFreezeLog>),
+    TokenUnfreeze(Vec<TokenFreezeLog>),
+    MysteryBoxReveal(Vec<MysteryBoxRevealLog>),
+    NftCraft(Vec<NftCraftLog>),
+    BadgeAward(Vec<BadgeAwardLog>),
+    TicketCheckIn(Vec<TicketCheckInLog>),
+    TokenMetadataUpdate(Vec<TokenMetadataUpdateLog>),
+    RecoveryRequest(Vec<RecoveryLog>),
+    RecoveryVeto(Vec<RecoveryLog>),
+    RecoveryCancel(Vec<RecoveryLog>),
+    RecoveryExecute(Vec<RecoveryLog>),
+}
+
+// 인덱서가 읽어가는 이벤트 로그. `EVENT_JSON:` 접두사와 함께 출력됨
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct EventLog {
+    pub standard: String,
+    pub version: String,
+
+    #[serde(flatten)]
+    pub event: EventLogVariant,
+}
+
+impl fmt::Display for EventLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_fmt(format_args!(
+            "EVENT_JSON:{}",
+            &near_sdk::serde_json::to_string(self).map_err(|_| fmt::Error)?
+        ))
+    }
+}
+
+impl EventLog {
+    // NEP-171 표준 이벤트
+    pub(crate) fn nep171(event: EventLogVariant) -> Self {
+        Self {
+            standard: NFT_STANDARD_NAME.to_string(),
+            version: NFT_STANDARD_VERSION.to_string(),
+            event,
+        }
+    }
+
+    // Mint Culture 고유 이벤트
+    pub(crate) fn mint_culture(event: EventLogVariant) -> Self {
+        Self {
+            standard: MINT_CULTURE_STANDARD_NAME.to_string(),
+            version: MINT_CULTURE_STANDARD_VERSION.to_string(),
+            event,
+        }
+    }
+
+    pub(crate) fn emit(&self) {
+        env::log_str(&self.to_string());
+    }
+}
+
+// 컨트랙트 메타데이터가 변경되었을 때의 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ContractMetadataUpdateLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+// NEP-171 토큰 민팅 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftMintLog {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<TokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+// NEP-171 토큰 전송 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftTransferLog {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    pub old_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub token_ids: Vec<TokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+// NEP-171 토큰 소각 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftBurnLog {
+    pub owner_id: AccountId,
+    pub token_ids: Vec<TokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorized_id: Option<AccountId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
+
+// 전송 제안(에스크로 전송)의 각 단계 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftTransferOfferLog {
+    pub token_id: TokenId,
+    pub sender_id: AccountId,
+    pub receiver_id: AccountId,
+    pub expires_at: U64,
+}
+
+// 계정 차단/차단 해제 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct AccountBlockLog {
+    pub account_id: AccountId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<BlockReason>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+    pub moderator_id: AccountId,
+}
+
+// 토큰 동결/동결 해제 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenFreezeLog {
+    pub token_id: TokenId,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    pub moderator_id: AccountId,
+}
+
+// 토큰 복구의 각 단계 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryLog {
+    pub token_id: TokenId,
+    pub previous_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    pub evidence_hash: Base64VecU8,
+    pub executable_at: U64,
+}
+
+// 미스터리 박스를 열어 나온 등급 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MysteryBoxRevealLog {
+    pub box_id: MysteryBoxId,
+    pub token_id: TokenId,
+    pub owner_id: AccountId,
+    pub tier: String,
+}
+
+// 입력 토큰들을 소각하고 결과 토큰을 민팅한 제작 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct NftCraftLog {
+    pub owner_id: AccountId,
+    pub recipe_id: RecipeId,
+    pub burned_token_ids: Vec<TokenId>,
+    pub token_id: TokenId,
+}
+
+// 업적 배지가 발급되었을 때의 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BadgeAwardLog {
+    pub owner_id: AccountId,
+    pub token_id: TokenId,
+    pub streamer_name: String,
+    pub threshold: u32,
+}
+
+// 입장권이 입장 처리되었을 때의 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TicketCheckInLog {
+    pub token_id: TokenId,
+    pub owner_id: AccountId,
+    pub event_id: String,
+    pub scanner_id: AccountId,
+}
+
+// 토큰 메타데이터가 바뀌었을 때의 로그
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenMetadataUpdateLog {
+    pub token_ids: Vec<TokenId>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memo: Option<String>,
+}
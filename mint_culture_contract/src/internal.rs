@@ -17,11 +17,24 @@ pub(crate) fn refund_approved_account_ids_iter<'a, I>(
     Promise::new(account_id).transfer(Balance::from(storage_released) * env::storage_byte_cost())
 }
 
+// 승인 목록과 승인 만료 시각이 차지하던 스토리지를 환불
 pub(crate) fn refund_approved_account_ids(
     account_id: AccountId,
     approved_account_ids: &HashMap<AccountId, u64>,
+    approval_expires_at: &HashMap<AccountId, u64>,
 ) -> Promise {
-    refund_approved_account_ids_iter(account_id, approved_account_ids.keys())
+    refund_approved_account_ids_iter(
+        account_id,
+        approved_account_ids.keys().chain(approval_expires_at.keys()),
+    )
+}
+
+// initial_storage_usage 이후 줄어든 스토리지 만큼 환불
+pub(crate) fn refund_released_storage(account_id: AccountId, initial_storage_usage: u64) {
+    let storage_released = initial_storage_usage.saturating_sub(env::storage_usage());
+    if storage_released > 0 {
+        Promise::new(account_id).transfer(Balance::from(storage_released) * env::storage_byte_cost());
+    }
 }
 
 // 스토리지 컬렉션에서 고유한 접두사를 생성하는 데 사용됩니다(데이터 충돌을 방지하기 위한 것임)
@@ -32,6 +45,13 @@ pub(crate) fn hash_account_id(account_id: &AccountId) -> CryptoHash {
     hash
 }
 
+pub(crate) fn hash_token_id(token_id: &TokenId) -> CryptoHash {
+    let mut hash = CryptoHash::default();
+
+    hash.copy_from_slice(&env::sha256(token_id.as_bytes()));
+    hash
+}
+
 pub(crate) fn assert_at_least_one_yocto() {
     assert!(
         env::attached_deposit() >= 1,
@@ -70,8 +90,36 @@ pub(crate) fn refund_deposit(storage_used: u64) {
     }
 }
 
+// 판매 가격을 payee 에게 보내고, 가격과 사용한 스토리지 비용을 제외한 보증금을 환불
+pub(crate) fn charge_price_and_storage(storage_used: u64, price: Balance, payee: AccountId) {
+    let storage_cost = env::storage_byte_cost() * Balance::from(storage_used);
+    let attached_deposit = env::attached_deposit();
+    assert!(
+        price + storage_cost <= attached_deposit,
+        "Must attach {} yoctoNEAR to cover the price and storage",
+        price + storage_cost,
+    );
+
+    if price > 0 {
+        Promise::new(payee).transfer(price);
+    }
+    let refund = attached_deposit - price - storage_cost;
+    if refund > 1 {
+        Promise::new(env::predecessor_account_id()).transfer(refund);
+    }
+}
+
 impl Contract {
 
+    // 컨트랙트 소유자만 호출할 수 있는 함수에서 사용
+    pub(crate) fn assert_owner(&self) {
+        assert_eq!(
+            &env::predecessor_account_id(),
+            &self.owner_id,
+            "Only the contract owner can call this method"
+        );
+    }
+
     // 소유자가 가지고 있는 토큰 세트에 토큰을 추가합니다.
     pub(crate) fn internal_add_token_to_owner(
         &mut self,
@@ -97,6 +145,52 @@ impl Contract {
 
         // 주어진 account ID에 set를 삽입
         self.tokens_per_owner.insert(account_id, &tokens_set);
+
+        self.internal_add_token_to_owner_by_mint_seq(account_id, token_id);
+        self.internal_update_held_per_streamer(account_id, token_id, true);
+    }
+
+    // 민팅 순번이 있는 토큰이면 소유자별 순서 인덱스에 추가합니다.
+    pub(crate) fn internal_add_token_to_owner_by_mint_seq(
+        &mut self,
+        account_id: &AccountId,
+        token_id: &TokenId,
+    ) {
+        if let Some(mint_seq) = self.mint_seq_by_id.get(token_id) {
+            let mut tokens_by_seq = self
+                .tokens_per_owner_by_mint_seq
+                .get(account_id)
+                .unwrap_or_else(|| {
+                    TreeMap::new(
+                        StorageKey::TokensPerOwnerByMintSeqInner {
+                            account_id_hash: hash_account_id(account_id),
+                        }
+                        .try_to_vec()
+                        .unwrap(),
+                    )
+                });
+            tokens_by_seq.insert(&mint_seq, token_id);
+            self.tokens_per_owner_by_mint_seq.insert(account_id, &tokens_by_seq);
+        }
+    }
+
+    // operator_id 가 owner_id 의 모든 토큰에 대한 오퍼레이터인지 확인
+    pub(crate) fn internal_is_operator(&self, owner_id: &AccountId, operator_id: &AccountId) -> bool {
+        self.operators_per_owner
+            .get(owner_id)
+            .map(|operators| operators.contains(operator_id))
+            .unwrap_or(false)
+    }
+
+    // 토큰에 다음 민팅 순번을 부여하고 전체 순서 인덱스에 추가합니다.
+    // 소유자별 인덱스에 반영되도록 internal_add_token_to_owner 보다 먼저 호출해야 합니다.
+    pub(crate) fn internal_assign_mint_seq(&mut self, token_id: &TokenId) -> u64 {
+        let mint_seq = self.next_mint_seq;
+        self.next_mint_seq += 1;
+
+        self.mint_seq_by_id.insert(token_id, &mint_seq);
+        self.tokens_by_mint_seq.insert(&mint_seq, token_id);
+        mint_seq
     }
     pub(crate) fn internal_transfer(
         &mut self,
@@ -106,12 +200,33 @@ impl Contract {
         approval_id: Option<u64>,
         memo: Option<String>
     ) -> Token {
+        self.assert_migrated();
         let token = self.tokens_by_id.get(token_id).expect("No Exist Token");
 
+        // 드롭의 전송 잠금이 풀리기 전에는 전송할 수 없음
+        self.assert_transfer_unlocked(token_id);
+        // 전송 제안 중인 토큰은 수락/취소 전까지 전송할 수 없음
+        self.assert_no_transfer_offer(token_id);
+        // 동결된 토큰은 전송할 수 없음
+        self.assert_not_frozen(token_id);
+        // 업적 배지는 전송할 수 없음
+        self.assert_not_soulbound(token_id);
+        // 입장 처리된 입장권은 전송할 수 없음
+        self.assert_ticket_not_redeemed(token_id);
+        // 차단된 계정은 토큰을 보내거나 받을 수 없음
+        self.assert_not_blocked(sender_id);
+        self.assert_not_blocked(&token.owner_id);
+        self.assert_not_blocked(receiver_id);
+        // 받는 사람이 스트리머별 보유 제한을 넘지 않아야 함
+        self.assert_within_held_limit(receiver_id, token_id);
+
         if sender_id != &token.owner_id {
 
-            if !token.approved_account_ids.contains_key(sender_id) {
-                env::panic_str("Unauthorized");
+            // 오퍼레이터는 approval_id 가 주어지지 않은 경우 소유자의 모든 토큰을 이동할 수 있음
+            let is_operator = self.internal_is_operator(&token.owner_id, sender_id);
+            if approval_id.is_some() || !is_operator {
+                // 만료된 승인은 없는 것으로 취급
+                assert!(token.active_approval_id(sender_id).is_some(), "Unauthorized");
             }
             if let Some(enforced_approval_id) = approval_id {
                 //get the actual approval ID
@@ -134,6 +249,25 @@ impl Contract {
             &token.owner_id, receiver_id,
             "토큰 소유자와 토큰 수신자는 달라야 합니다."
         );
+
+        // 소유자가 아닌 계정(승인된 계정, 오퍼레이터)이 전송한 경우 authorized_id 에 기록
+        let authorized_id = if sender_id != &token.owner_id {
+            Some(sender_id.clone())
+        } else {
+            None
+        };
+        self.internal_move_token(token, receiver_id, token_id, authorized_id, memo)
+    }
+
+    // 전송 조건 확인이 끝난 토큰을 받는 사람에게 옮기고 이력과 이벤트를 남깁니다. 이전 토큰 정보를 반환합니다.
+    pub(crate) fn internal_move_token(
+        &mut self,
+        token: Token,
+        receiver_id: &AccountId,
+        token_id: &TokenId,
+        authorized_id: Option<AccountId>,
+        memo: Option<String>,
+    ) -> Token {
         // 보내는 사람 토큰 목록에서 제거
         self.internal_remove_token_from_owner(&token.owner_id, token_id);
         // 받는 사람 토큰 목록에 추가
@@ -143,13 +277,26 @@ impl Contract {
         let new_token = Token {
             owner_id: receiver_id.clone(),
             approved_account_ids: Default::default(),
+            approval_expires_at: Default::default(),
             next_approval_id: token.next_approval_id,
         };
 
         self.tokens_by_id.insert(token_id, &new_token);
-        if let Some(memo) = memo {
+        self.internal_record_provenance(token_id, receiver_id, ProvenanceKind::Transfer, None);
+        self.internal_award_badges(receiver_id, token_id);
+
+        if let Some(memo) = memo.as_ref() {
             env::log_str(&format!("Memo: {}", memo).to_string());
         }
+
+        EventLog::nep171(EventLogVariant::NftTransfer(vec![NftTransferLog {
+            authorized_id,
+            old_owner_id: token.owner_id.clone(),
+            new_owner_id: receiver_id.clone(),
+            token_ids: vec![token_id.clone()],
+            memo,
+        }]))
+        .emit();
         token
     }
     pub(crate) fn internal_remove_token_from_owner(
@@ -163,10 +310,22 @@ impl Contract {
             .expect("Token should be owned by the sender");
 
         tokens_set.remove(token_id);
+        self.internal_update_held_per_streamer(account_id, token_id, false);
         if tokens_set.is_empty() {
             self.tokens_per_owner.remove(account_id);
         } else {
             self.tokens_per_owner.insert(account_id, &tokens_set);
         }
+
+        if let Some(mint_seq) = self.mint_seq_by_id.get(token_id) {
+            if let Some(mut tokens_by_seq) = self.tokens_per_owner_by_mint_seq.get(account_id) {
+                tokens_by_seq.remove(&mint_seq);
+                if tokens_by_seq.is_empty() {
+                    self.tokens_per_owner_by_mint_seq.remove(account_id);
+                } else {
+                    self.tokens_per_owner_by_mint_seq.insert(account_id, &tokens_by_seq);
+                }
+            }
+        }
     }
 }
\ No newline at end of file
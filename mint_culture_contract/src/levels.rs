@@ -0,0 +1,197 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, hash_token_id, refund_deposit};
+
+// 레벨에 따라 토큰에 붙는 속성
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LevelTrait {
+    pub trait_type: String,
+    pub value: String,
+}
+
+// 드롭의 레벨 표에 있는 레벨 하나. 표의 첫 항목이 레벨 1이고 민팅 직후의 토큰은 레벨 0
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LevelSpec {
+    // 이 레벨에 도달하는 데 필요한 누적 참여도 (시청 시간, 후원 등 오라클이 정하는 단위)
+    pub min_engagement: U64,
+    // 이 레벨의 토큰 미디어
+    pub media: String,
+    pub media_hash: Option<Base64VecU8>,
+    // 이 레벨에 도달하면 추가되는 속성. 같은 trait_type 이 이미 있으면 값을 바꿈
+    pub traits: Vec<LevelTrait>,
+}
+
+// 토큰의 현재 레벨
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct TokenLevel {
+    pub level: u32,
+    // 오라클이 마지막으로 보고한 누적 참여도
+    pub engagement: U64,
+    pub traits: Vec<LevelTrait>,
+}
+
+// 레벨업으로 바뀌기 전의 레벨
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct LevelHistoryEntry {
+    pub level: u32,
+    pub media: String,
+    pub media_hash: Option<Base64VecU8>,
+    pub traits: Vec<LevelTrait>,
+    // 다음 레벨로 바뀐 시각 (나노초)
+    pub replaced_at: U64,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 참여도를 보고하는 오라클 계정 변경 (소유자만 가능). None 이면 보고를 받지 않음
+    #[payable]
+    pub fn set_level_oracle(&mut self, oracle_id: Option<AccountId>) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.level_oracle = oracle_id;
+    }
+
+    pub fn get_level_oracle(&self) -> Option<AccountId> {
+        self.level_oracle.clone()
+    }
+
+    // 드롭의 레벨 표 설정 (소유자만 가능). 이미 도달한 레벨은 내려가지 않음
+    #[payable]
+    pub fn set_level_table(&mut self, drop_id: DropId, levels: Vec<LevelSpec>) {
+        self.assert_owner();
+        assert!(self.drops.get(&drop_id).is_some(), "Drop {} does not exist", drop_id);
+        let mut previous_engagement = 0;
+        for (index, spec) in levels.iter().enumerate() {
+            assert!(
+                spec.min_engagement.0 > previous_engagement,
+                "Level {} must require more engagement than the previous level",
+                index + 1
+            );
+            previous_engagement = spec.min_engagement.0;
+            self.metadata_limits.assert_valid_media(&spec.media, spec.media_hash.as_ref());
+        }
+
+        let initial_storage_usage = env::storage_usage();
+        self.level_tables.insert(&drop_id, &levels);
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+    }
+
+    pub fn get_level_table(&self, drop_id: DropId) -> Option<Vec<LevelSpec>> {
+        self.level_tables.get(&drop_id)
+    }
+
+    // 오라클이 토큰 소유자의 누적 참여도를 보고합니다. 다음 레벨 기준을 넘으면 미디어와 속성이 바뀝니다.
+    // 늘어난 스토리지 비용을 첨부해야 하며 토큰의 레벨을 반환합니다.
+    #[payable]
+    pub fn report_engagement(&mut self, token_id: TokenId, engagement: U64) -> u32 {
+        assert_eq!(
+            Some(env::predecessor_account_id()),
+            self.level_oracle,
+            "Only the level oracle can report engagement"
+        );
+        assert!(
+            self.unrevealed_series_by_token.get(&token_id).is_none(),
+            "Token {} has not been revealed yet",
+            token_id
+        );
+        let drop_id = self
+            .drop_by_token
+            .get(&token_id)
+            .unwrap_or_else(|| panic!("Token {} does not belong to a drop", token_id));
+        let levels = self
+            .level_tables
+            .get(&drop_id)
+            .unwrap_or_else(|| panic!("Drop {} has no level table", drop_id));
+
+        let initial_storage_usage = env::storage_usage();
+        let mut token_level = self.token_levels.get(&token_id).unwrap_or(TokenLevel {
+            level: 0,
+            engagement: U64(0),
+            traits: vec![],
+        });
+        assert!(
+            engagement.0 >= token_level.engagement.0,
+            "Engagement of token {} cannot decrease",
+            token_id
+        );
+        token_level.engagement = engagement;
+
+        let reached = levels
+            .iter()
+            .take_while(|spec| spec.min_engagement.0 <= engagement.0)
+            .count() as u32;
+        if reached > token_level.level {
+            let mut metadata = self.token_metadata_by_id.get(&token_id).expect("No token");
+            self.internal_record_level(&token_id, &metadata, &token_level);
+
+            for spec in &levels[token_level.level as usize..reached as usize] {
+                for level_trait in &spec.traits {
+                    match token_level.traits.iter_mut().find(|t| t.trait_type == level_trait.trait_type) {
+                        Some(existing) => existing.value = level_trait.value.clone(),
+                        None => token_level.traits.push(level_trait.clone()),
+                    }
+                }
+            }
+            let spec = &levels[reached as usize - 1];
+            metadata.media = spec.media.clone();
+            metadata.media_hash = spec.media_hash.clone();
+            metadata.assert_valid(&self.metadata_limits);
+
+            self.internal_replace_token_metadata(&token_id, metadata);
+            token_level.level = reached;
+
+            EventLog::mint_culture(EventLogVariant::TokenMetadataUpdate(vec![TokenMetadataUpdateLog {
+                token_ids: vec![token_id.clone()],
+                memo: Some(format!("level:{}", reached)),
+            }]))
+            .emit();
+        }
+        self.token_levels.insert(&token_id, &token_level);
+
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+        token_level.level
+    }
+
+    pub fn nft_token_level(&self, token_id: TokenId) -> Option<TokenLevel> {
+        self.token_levels.get(&token_id)
+    }
+
+    // 토큰이 거쳐온 이전 레벨들 (오래된 순)
+    pub fn nft_level_history(&self, token_id: TokenId, from_index: Option<U128>, limit: Option<u64>) -> Vec<LevelHistoryEntry> {
+        let history = if let Some(history) = self.level_history.get(&token_id) {
+            history
+        } else {
+            return vec![];
+        };
+
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        history.iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .collect()
+    }
+}
+
+impl Contract {
+    // 바뀌기 전 레벨의 미디어와 속성을 이력에 남김
+    fn internal_record_level(&mut self, token_id: &TokenId, metadata: &TokenMetadata, token_level: &TokenLevel) {
+        let mut history = self.level_history.get(token_id).unwrap_or_else(|| {
+            Vector::new(StorageKey::LevelHistoryInner {
+                token_id_hash: hash_token_id(token_id),
+            }
+            .try_to_vec()
+            .unwrap())
+        });
+        history.push(&LevelHistoryEntry {
+            level: token_level.level,
+            media: metadata.media.clone(),
+            media_hash: metadata.media_hash.clone(),
+            traits: token_level.traits.clone(),
+            replaced_at: U64(env::block_timestamp()),
+        });
+        self.level_history.insert(token_id, &history);
+    }
+}
@@ -1,10 +1,10 @@
 use std::collections::HashMap;
 use near_sdk::borsh::{self, BorshDeserialize, BorshSerialize};
-use near_sdk::collections::{LazyOption, LookupMap, UnorderedMap, UnorderedSet};
-use near_sdk::json_types::{Base64VecU8, U128};
+use near_sdk::collections::{LazyOption, LookupMap, LookupSet, TreeMap, UnorderedMap, UnorderedSet, Vector};
+use near_sdk::json_types::{Base64VecU8, U128, U64};
 use near_sdk::serde::{Deserialize, Serialize};
 use near_sdk::{
-    env, near_bindgen, AccountId, Balance, CryptoHash, PanicOnDefault, Promise, PromiseOrValue,
+    env, near_bindgen, AccountId, Balance, CryptoHash, PanicOnDefault, Promise, PromiseOrValue, PublicKey,
 };
 
 pub use crate::metadata::*;
@@ -12,6 +12,25 @@ pub use crate::mint::*;
 pub use crate::nft_core::*;
 pub use crate::approval::*;
 pub use crate::royalty::*;
+pub use crate::events::*;
+pub use crate::validation::*;
+pub use crate::drops::*;
+pub use crate::escrow::*;
+pub use crate::moderation::*;
+pub use crate::recovery::*;
+pub use crate::provenance::*;
+pub use crate::limits::*;
+pub use crate::presale::*;
+pub use crate::vouchers::*;
+pub use crate::claims::*;
+pub use crate::mystery_box::*;
+pub use crate::reveal::*;
+pub use crate::levels::*;
+pub use crate::crafting::*;
+pub use crate::badges::*;
+pub use crate::tickets::*;
+pub use crate::storage::*;
+pub use crate::migrate::{MigrationStatus, OldTokenMetadata, STATE_VERSION};
 
 
 mod approval;
@@ -21,6 +40,25 @@ mod mint;
 mod nft_core;
 mod royalty;
 mod internal;
+mod events;
+mod validation;
+mod migrate;
+mod drops;
+mod escrow;
+mod moderation;
+mod recovery;
+mod provenance;
+mod limits;
+mod presale;
+mod vouchers;
+mod claims;
+mod mystery_box;
+mod reveal;
+mod levels;
+mod crafting;
+mod badges;
+mod tickets;
+mod storage;
 
 #[near_bindgen]
 #[derive(BorshDeserialize, BorshSerialize, PanicOnDefault)]
@@ -35,6 +73,96 @@ pub struct Contract {
     pub token_metadata_by_id: UnorderedMap<TokenId, TokenMetadata>,
     // 계약에 관한 메타데이터를 추적함
     pub metadata: LazyOption<NFTContractMetadata>,
+    // 토큰 메타데이터 검증 제한값
+    pub metadata_limits: MetadataLimits,
+    // 다음에 민팅될 토큰에 부여할 순번
+    pub next_mint_seq: u64,
+    // 토큰 ID에 관한 민팅 순번
+    pub mint_seq_by_id: LookupMap<TokenId, u64>,
+    // 민팅 순번에 관한 토큰 ID. 커서 기반 페이지네이션의 안정적인 순서로 사용
+    pub tokens_by_mint_seq: TreeMap<u64, TokenId>,
+    // 주어진 계정이 가진 토큰을 민팅 순번 순서로 추적
+    pub tokens_per_owner_by_mint_seq: LookupMap<AccountId, TreeMap<u64, TokenId>>,
+    // 블록 타임스탬프에 관한 그 시각에 처음 민팅된 토큰의 순번. 시각 구간 조회에 사용
+    pub first_mint_seq_by_timestamp: TreeMap<u64, u64>,
+    // 주어진 계정의 모든 토큰을 대신 이동할 수 있는 오퍼레이터 계정들
+    pub operators_per_owner: LookupMap<AccountId, UnorderedSet<AccountId>>,
+    // 토큰 하나에 등록할 수 있는 최대 승인 계정 수
+    pub max_approvals_per_token: u32,
+    // 드롭 ID에 관한 드롭 설정
+    pub drops: UnorderedMap<DropId, DropConfig>,
+    // 토큰 ID에 관한 토큰이 민팅된 드롭
+    pub drop_by_token: LookupMap<TokenId, DropId>,
+    // 토큰 ID에 관한 수락 대기 중인 전송 제안
+    pub transfer_offers: LookupMap<TokenId, TransferOffer>,
+    // 차단된 계정을 관리하는 모더레이터들
+    pub moderators: UnorderedSet<AccountId>,
+    // 차단된 계정에 관한 차단 정보
+    pub blocked_accounts: UnorderedMap<AccountId, BlockedAccount>,
+    // 토큰 ID에 관한 동결 정보
+    pub frozen_tokens: LookupMap<TokenId, FrozenToken>,
+    // 복구 요청 후 실행할 수 있을 때까지 소유자가 거부할 수 있는 기간 (나노초)
+    pub recovery_delay: u64,
+    // 토큰 ID에 관한 복구 요청 기록
+    pub recovery_records: LookupMap<TokenId, Vector<RecoveryRecord>>,
+    // 토큰 ID에 관한 소유 이력 (소각 후에도 남음)
+    pub token_history: LookupMap<TokenId, Vector<ProvenanceEntry>>,
+    // 민팅 및 보유 수량 제한
+    pub mint_limits: MintLimits,
+    // (드롭 ID, 계정)에 관한 드롭에서 받은 토큰 수
+    pub drop_mints_per_account: LookupMap<(DropId, AccountId), u32>,
+    // (계정, 스트리머 이름)에 관한 보유 토큰 수
+    pub held_per_streamer: LookupMap<(AccountId, String), u32>,
+    // 프리세일 단계 ID에 관한 단계 설정
+    pub sale_phases: UnorderedMap<SalePhaseId, SalePhase>,
+    // (단계 ID, 계정)에 관한 프리세일에서 민팅한 토큰 수
+    pub presale_claims: LookupMap<(SalePhaseId, AccountId), u32>,
+    // 지연 민팅 바우처를 서명하는 백엔드 키
+    pub voucher_signer: Option<PublicKey>,
+    // 이미 사용된 바우처 nonce
+    pub used_voucher_nonces: LookupSet<u64>,
+    // 클레임 템플릿 ID에 관한 민팅될 토큰 메타데이터
+    pub claim_templates: UnorderedMap<ClaimTemplateId, TokenMetadata>,
+    // 클레임 공개 키에 관한 사용되지 않은 클레임 코드
+    pub claim_codes: LookupMap<Vec<u8>, ClaimCode>,
+    // 미스터리 박스 ID에 관한 등급 설정과 등급별 민팅 수
+    pub mystery_boxes: UnorderedMap<MysteryBoxId, MysteryBox>,
+    // 공개 시리즈 ID에 관한 대체 메타데이터와 최종 메타데이터 커밋
+    pub reveal_series: UnorderedMap<RevealSeriesId, RevealSeries>,
+    // 토큰 ID에 관한 아직 공개되지 않은 토큰의 시리즈
+    pub unrevealed_series_by_token: LookupMap<TokenId, RevealSeriesId>,
+    // 참여도를 보고하는 백엔드 오라클 계정
+    pub level_oracle: Option<AccountId>,
+    // 드롭 ID에 관한 레벨 표
+    pub level_tables: LookupMap<DropId, Vec<LevelSpec>>,
+    // 토큰 ID에 관한 현재 레벨과 참여도
+    pub token_levels: LookupMap<TokenId, TokenLevel>,
+    // 토큰 ID에 관한 이전 레벨 이력
+    pub level_history: LookupMap<TokenId, Vector<LevelHistoryEntry>>,
+    // 토큰 ID에 관한 희귀도 등급. 미스터리 박스나 제작으로 민팅된 토큰에만 있음
+    pub token_tiers: LookupMap<TokenId, String>,
+    // 레시피 ID에 관한 제작 레시피
+    pub crafting_recipes: UnorderedMap<RecipeId, CraftingRecipe>,
+    // 보유 수 기준이 낮은 순으로 정렬된 업적 배지 설정
+    pub achievement_badges: Vec<AchievementBadge>,
+    // (계정, 스트리머 이름, 기준)에 관한 발급된 배지 토큰 ID
+    pub awarded_badges: LookupMap<(AccountId, String, u32), TokenId>,
+    // 전송할 수 없는 배지 토큰
+    pub badge_tokens: LookupSet<TokenId>,
+    // 토큰 ID에 관한 입장권 정보
+    pub tickets: LookupMap<TokenId, Ticket>,
+    // 입장권을 입장 처리할 수 있는 현장 스캐너 계정
+    pub venue_scanners: UnorderedSet<AccountId>,
+    // 상태 구조 버전. 이전 상태에서 토큰을 옮기는 중이면 STATE_VERSION 보다 작음
+    pub state_version: u32,
+    // 아직 새 구조로 옮기지 않은 이전 토큰 메타데이터
+    pub legacy_token_metadata: Option<UnorderedMap<TokenId, OldTokenMetadata>>,
+    // 소유자 대신 토큰을 직접 민팅할 수 있는 계정
+    pub minters: UnorderedSet<AccountId>,
+    // 지금까지 민팅된 토큰 수 (소각된 토큰 포함). 업적 배지는 세지 않으며 max_supply 확인에 사용
+    pub minted_supply: u64,
+    // 계정별로 미리 낸 스토리지 잔액 (NEP-145). NEP-171 전송의 소유 이력 스토리지를 여기서 차감함
+    pub storage_balances: LookupMap<AccountId, Balance>,
 }
 
 /// Helper structure for keys of the persistent collections.
@@ -48,6 +176,46 @@ pub enum StorageKey {
     TokensPerType,
     TokensPerTypeInner { token_type_hash: CryptoHash },
     TokenTypesLocked,
+    MintSeqById,
+    TokensByMintSeq,
+    TokensPerOwnerByMintSeq,
+    TokensPerOwnerByMintSeqInner { account_id_hash: CryptoHash },
+    FirstMintSeqByTimestamp,
+    OperatorsPerOwner,
+    OperatorsPerOwnerInner { account_id_hash: CryptoHash },
+    Drops,
+    DropByToken,
+    TransferOffers,
+    Moderators,
+    BlockedAccounts,
+    FrozenTokens,
+    RecoveryRecords,
+    RecoveryRecordsInner { token_id_hash: CryptoHash },
+    TokenHistory,
+    TokenHistoryInner { token_id_hash: CryptoHash },
+    DropMintsPerAccount,
+    HeldPerStreamer,
+    SalePhases,
+    PresaleClaims,
+    UsedVoucherNonces,
+    ClaimTemplates,
+    ClaimCodes,
+    MysteryBoxes,
+    RevealSeries,
+    UnrevealedSeriesByToken,
+    LevelTables,
+    TokenLevels,
+    LevelHistory,
+    LevelHistoryInner { token_id_hash: CryptoHash },
+    TokenTiers,
+    CraftingRecipes,
+    AwardedBadges,
+    BadgeTokens,
+    Tickets,
+    VenueScanners,
+    TokenMetadataByIdV2,
+    Minters,
+    StorageBalances,
 }
 
 #[near_bindgen]
@@ -74,12 +242,62 @@ impl Contract {
         let this = Self {
             tokens_per_owner: LookupMap::new(StorageKey::TokensPerOwner.try_to_vec().unwrap()),
             tokens_by_id: LookupMap::new(StorageKey::TokensById.try_to_vec().unwrap()),
-            token_metadata_by_id: UnorderedMap::new(StorageKey::TokenMetadataById.try_to_vec().unwrap()),
+            // 이전 구조의 메타데이터가 TokenMetadataById 에 남아 있을 수 있으므로 새 접두사를 사용
+            token_metadata_by_id: UnorderedMap::new(StorageKey::TokenMetadataByIdV2.try_to_vec().unwrap()),
             owner_id,
             metadata: LazyOption::new(
                 StorageKey::NFTContractMetadata.try_to_vec().unwrap(),
                 Some(&metadata)
             ),
+            metadata_limits: MetadataLimits::default(),
+            next_mint_seq: 0,
+            mint_seq_by_id: LookupMap::new(StorageKey::MintSeqById.try_to_vec().unwrap()),
+            tokens_by_mint_seq: TreeMap::new(StorageKey::TokensByMintSeq.try_to_vec().unwrap()),
+            tokens_per_owner_by_mint_seq: LookupMap::new(
+                StorageKey::TokensPerOwnerByMintSeq.try_to_vec().unwrap(),
+            ),
+            first_mint_seq_by_timestamp: TreeMap::new(
+                StorageKey::FirstMintSeqByTimestamp.try_to_vec().unwrap(),
+            ),
+            operators_per_owner: LookupMap::new(StorageKey::OperatorsPerOwner.try_to_vec().unwrap()),
+            max_approvals_per_token: DEFAULT_MAX_APPROVALS_PER_TOKEN,
+            drops: UnorderedMap::new(StorageKey::Drops.try_to_vec().unwrap()),
+            drop_by_token: LookupMap::new(StorageKey::DropByToken.try_to_vec().unwrap()),
+            transfer_offers: LookupMap::new(StorageKey::TransferOffers.try_to_vec().unwrap()),
+            moderators: UnorderedSet::new(StorageKey::Moderators.try_to_vec().unwrap()),
+            blocked_accounts: UnorderedMap::new(StorageKey::BlockedAccounts.try_to_vec().unwrap()),
+            frozen_tokens: LookupMap::new(StorageKey::FrozenTokens.try_to_vec().unwrap()),
+            recovery_delay: DEFAULT_RECOVERY_DELAY,
+            recovery_records: LookupMap::new(StorageKey::RecoveryRecords.try_to_vec().unwrap()),
+            token_history: LookupMap::new(StorageKey::TokenHistory.try_to_vec().unwrap()),
+            mint_limits: MintLimits::default(),
+            drop_mints_per_account: LookupMap::new(StorageKey::DropMintsPerAccount.try_to_vec().unwrap()),
+            held_per_streamer: LookupMap::new(StorageKey::HeldPerStreamer.try_to_vec().unwrap()),
+            sale_phases: UnorderedMap::new(StorageKey::SalePhases.try_to_vec().unwrap()),
+            presale_claims: LookupMap::new(StorageKey::PresaleClaims.try_to_vec().unwrap()),
+            voucher_signer: None,
+            used_voucher_nonces: LookupSet::new(StorageKey::UsedVoucherNonces.try_to_vec().unwrap()),
+            claim_templates: UnorderedMap::new(StorageKey::ClaimTemplates.try_to_vec().unwrap()),
+            claim_codes: LookupMap::new(StorageKey::ClaimCodes.try_to_vec().unwrap()),
+            mystery_boxes: UnorderedMap::new(StorageKey::MysteryBoxes.try_to_vec().unwrap()),
+            reveal_series: UnorderedMap::new(StorageKey::RevealSeries.try_to_vec().unwrap()),
+            unrevealed_series_by_token: LookupMap::new(StorageKey::UnrevealedSeriesByToken.try_to_vec().unwrap()),
+            level_oracle: None,
+            level_tables: LookupMap::new(StorageKey::LevelTables.try_to_vec().unwrap()),
+            token_levels: LookupMap::new(StorageKey::TokenLevels.try_to_vec().unwrap()),
+            level_history: LookupMap::new(StorageKey::LevelHistory.try_to_vec().unwrap()),
+            token_tiers: LookupMap::new(StorageKey::TokenTiers.try_to_vec().unwrap()),
+            crafting_recipes: UnorderedMap::new(StorageKey::CraftingRecipes.try_to_vec().unwrap()),
+            achievement_badges: vec![],
+            awarded_badges: LookupMap::new(StorageKey::AwardedBadges.try_to_vec().unwrap()),
+            badge_tokens: LookupSet::new(StorageKey::BadgeTokens.try_to_vec().unwrap()),
+            tickets: LookupMap::new(StorageKey::Tickets.try_to_vec().unwrap()),
+            venue_scanners: UnorderedSet::new(StorageKey::VenueScanners.try_to_vec().unwrap()),
+            state_version: STATE_VERSION,
+            legacy_token_metadata: None,
+            minters: UnorderedSet::new(StorageKey::Minters.try_to_vec().unwrap()),
+            minted_supply: 0,
+            storage_balances: LookupMap::new(StorageKey::StorageBalances.try_to_vec().unwrap()),
         };
         this
     }
@@ -88,8 +306,9 @@ impl Contract {
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod tests {
     use near_sdk::test_utils::{ accounts, VMContextBuilder };
-    use near_sdk::{ testing_env, Balance} ;
+    use near_sdk::{ testing_env, Balance, PromiseResult} ;
     use super::*;
+    use crate::migrate::{OldContract, OldToken, OldTokenMetadata};
 
 
     const ATTACHED_VALUE: Balance = 100_000_000_000_000_000_000_000;
@@ -147,7 +366,8 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: "".to_string(),
+            issued_at: U64(0),
+            legacy_issued_at: None,
         };
 
         contract.nft_mint(
@@ -168,6 +388,8 @@ mod tests {
         testing_env!(context.build());
 
         let mut contract = Contract::new_default_meta(accounts(1));
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.add_minter(accounts(2));
 
         let token_meta_data = TokenMetadata {
             streamer_name: "이름".to_string(),
@@ -176,7 +398,8 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: "".to_string(),
+            issued_at: U64(0),
+            legacy_issued_at: None,
         };
 
         testing_env!(context
@@ -230,7 +453,8 @@ mod tests {
             media: "https://주소".to_string(),
             media_hash: None,
             description: None,
-            issued_at: "오늘".to_string(),
+            issued_at: U64(0),
+            legacy_issued_at: None,
         };
         contract.nft_mint(
             "TOKEN_ID_1".to_string(),
@@ -259,16 +483,13 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: "".to_string(),
+            issued_at: U64(0),
+            legacy_issued_at: None,
         };
 
         let mut context = get_context(accounts(1));
-
-        testing_env!(context
-            .storage_usage(env::storage_usage())
-            .attached_deposit(ATTACHED_VALUE)
-            .predecessor_account_id(accounts(1))
-            .build());
+        testing_env!(context.attached_deposit(1).build());
+        contract.add_minter(accounts(2));
 
         testing_env!(context
             .storage_usage(env::storage_usage())
@@ -283,7 +504,8 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: token_meta_data.issued_at.clone()
+            issued_at: token_meta_data.issued_at,
+            legacy_issued_at: None,
         };
 
 
@@ -316,16 +538,13 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: "".to_string(),
+            issued_at: U64(0),
+            legacy_issued_at: None,
         };
 
         let mut context = get_context(accounts(1));
-
-        testing_env!(context
-            .storage_usage(env::storage_usage())
-            .attached_deposit(ATTACHED_VALUE)
-            .predecessor_account_id(accounts(1))
-            .build());
+        testing_env!(context.attached_deposit(1).build());
+        contract.add_minter(accounts(2));
 
         testing_env!(context
             .storage_usage(env::storage_usage())
@@ -340,7 +559,8 @@ mod tests {
             media: "https://mint.culture.com".to_string(),
             media_hash: None,
             description: None,
-            issued_at: token_meta_data.issued_at.clone()
+            issued_at: token_meta_data.issued_at,
+            legacy_issued_at: None,
         };
 
         contract.nft_mint(
@@ -390,4 +610,2326 @@ mod tests {
         assert_eq!(is_none, true);
     }
 
+    /// TEST for metadata.rs
+    fn rebranded_metadata() -> NFTContractMetadata {
+        NFTContractMetadata {
+            spec: "nft-1.0.0".to_string(),
+            name: "Mint Culture".to_string(),
+            symbol: "MINT".to_string(),
+            icon: Some("data:image/svg+xml,<svg></svg>".to_string()),
+            base_uri: Some("https://ipfs.mintculture.io/ipfs".to_string()),
+            reference: None,
+            reference_hash: None,
+        }
+    }
+
+    #[test]
+    fn test_set_contract_metadata() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.set_contract_metadata(rebranded_metadata(), Some("rebrand".to_string()));
+
+        let metadata = contract.nft_metadata();
+        assert_eq!(metadata.name, "Mint Culture");
+        assert_eq!(metadata.base_uri.unwrap(), "https://ipfs.mintculture.io/ipfs");
+
+        let logs = near_sdk::test_utils::get_logs();
+        assert_eq!(
+            logs.last().unwrap(),
+            r#"EVENT_JSON:{"standard":"nep171","version":"1.1.0","event":"contract_metadata_update","data":[{"memo":"rebrand"}]}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the contract owner can call this method")]
+    fn test_set_contract_metadata_not_owner() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(2))
+            .build());
+        contract.set_contract_metadata(rebranded_metadata(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "reference_hash is required when reference is set")]
+    fn test_set_contract_metadata_reference_without_hash() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        let mut metadata = rebranded_metadata();
+        metadata.reference = Some("https://ipfs.mintculture.io/ipfs/collection.json".to_string());
+        contract.set_contract_metadata(metadata, None);
+    }
+
+    /// TEST for validation.rs
+    fn valid_token_metadata() -> TokenMetadata {
+        TokenMetadata {
+            streamer_name: "이름".to_string(),
+            title: "제목".to_string(),
+            serial_number: "1111222233334444".to_string(),
+            media: "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+            media_hash: Some(Base64VecU8(vec![0u8; 32])),
+            description: Some("설명".to_string()),
+            issued_at: U64(0),
+            legacy_issued_at: None,
+        }
+    }
+
+    fn mint_with_metadata(metadata: TokenMetadata) -> Contract {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_mint("TOKEN_ID_1".to_string(), metadata, accounts(1));
+        contract
+    }
+
+    #[test]
+    fn test_nft_mint_valid_metadata() {
+        let contract = mint_with_metadata(valid_token_metadata());
+        assert_eq!(contract.nft_total_supply(), U128(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.title must not be empty")]
+    fn test_nft_mint_empty_title() {
+        let mut metadata = valid_token_metadata();
+        metadata.title = "".to_string();
+        mint_with_metadata(metadata);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.media must start with one of")]
+    fn test_nft_mint_invalid_media_scheme() {
+        let mut metadata = valid_token_metadata();
+        metadata.media = "ftp://mint.culture.com/clip.mp4".to_string();
+        mint_with_metadata(metadata);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.description is 1025 bytes long, the maximum is 1024 bytes")]
+    fn test_nft_mint_description_too_long() {
+        let mut metadata = valid_token_metadata();
+        metadata.description = Some("a".repeat(1025));
+        mint_with_metadata(metadata);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.media_hash must be a base64-encoded sha256 hash (32 bytes)")]
+    fn test_nft_mint_invalid_media_hash() {
+        let mut metadata = valid_token_metadata();
+        metadata.media_hash = Some(Base64VecU8(vec![0u8; 16]));
+        mint_with_metadata(metadata);
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.serial_number must be exactly 16 digits")]
+    fn test_nft_mint_invalid_serial_number() {
+        let mut metadata = valid_token_metadata();
+        metadata.serial_number = "1111-2222-3333-4".to_string();
+        mint_with_metadata(metadata);
+    }
+
+    #[test]
+    fn test_set_metadata_limits() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        let mut limits = contract.get_metadata_limits();
+        limits.allowed_media_schemes = vec!["ipfs://".to_string()];
+        contract.set_metadata_limits(limits);
+
+        assert_eq!(contract.get_metadata_limits().allowed_media_schemes, vec!["ipfs://".to_string()]);
+    }
+
+    /// TEST for mint.rs, migrate.rs
+    #[test]
+    fn test_nft_mint_issued_at_from_block_timestamp() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(1_662_000_000_123_456_789)
+            .build());
+
+        let mut metadata = valid_token_metadata();
+        metadata.issued_at = U64(42);
+        metadata.legacy_issued_at = Some("오늘".to_string());
+        contract.nft_mint("TOKEN_ID_1".to_string(), metadata, accounts(1));
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.metadata.issued_at, U64(1_662_000_000_123_456_789));
+        assert_eq!(token.metadata.legacy_issued_at, None);
+        assert_eq!(token.issued_at_ms, 1_662_000_000_123);
+    }
+
+    // TOKEN_ID_1..count 를 가진 이전 상태를 저장하고 migrate 를 호출
+    fn migrate_legacy_contract(context: &mut VMContextBuilder, count: u32) -> Contract {
+        testing_env!(context.build());
+
+        let mut old = OldContract {
+            owner_id: accounts(1),
+            tokens_per_owner: LookupMap::new(StorageKey::TokensPerOwner.try_to_vec().unwrap()),
+            tokens_by_id: LookupMap::new(StorageKey::TokensById.try_to_vec().unwrap()),
+            token_metadata_by_id: UnorderedMap::new(StorageKey::TokenMetadataById.try_to_vec().unwrap()),
+            metadata: LazyOption::new(
+                StorageKey::NFTContractMetadata.try_to_vec().unwrap(),
+                Some(&rebranded_metadata()),
+            ),
+        };
+        for i in 1..=count {
+            let token_id = format!("TOKEN_ID_{}", i);
+            old.tokens_by_id.insert(
+                &token_id,
+                &OldToken {
+                    owner_id: accounts(2),
+                    approved_account_ids: Default::default(),
+                    next_approval_id: 0,
+                },
+            );
+            old.token_metadata_by_id.insert(
+                &token_id,
+                &OldTokenMetadata {
+                    streamer_name: "이름".to_string(),
+                    title: "제목".to_string(),
+                    serial_number: "1111222233334444".to_string(),
+                    media: "https://mint.culture.com".to_string(),
+                    media_hash: None,
+                    description: None,
+                    issued_at: "오늘".to_string(),
+                },
+            );
+        }
+        env::state_write(&old);
+
+        testing_env!(context.predecessor_account_id(accounts(0)).build());
+        Contract::migrate()
+    }
+
+    #[test]
+    fn test_nft_mint_accepts_legacy_issued_at_string() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        // 이전 클라이언트가 보내던 형식의 민팅 인자
+        let metadata: TokenMetadata = near_sdk::serde_json::from_str(
+            r#"{"streamer_name":"이름","title":"제목","serial_number":"1111222233334444","media":"ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi","media_hash":null,"description":null,"issued_at":"오늘"}"#,
+        )
+        .unwrap();
+        assert_eq!(metadata.issued_at, U64(0));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(1_000)
+            .build());
+        contract.nft_mint("TOKEN_ID_1".to_string(), metadata, accounts(1));
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().metadata.issued_at, U64(1_000));
+    }
+
+    #[test]
+    fn test_migrate_preserves_legacy_issued_at() {
+        let mut context = get_context(accounts(0));
+        let mut contract = migrate_legacy_contract(&mut context, 1);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        assert_eq!(contract.migrate_tokens(10), U64(0));
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.owner_id, accounts(2));
+        assert_eq!(token.metadata.title, "제목");
+        assert_eq!(token.metadata.issued_at, U64(0));
+        assert_eq!(token.metadata.legacy_issued_at.unwrap(), "오늘");
+        assert_eq!(contract.nft_metadata().name, "Mint Culture");
+        assert_eq!(contract.nft_total_supply(), U128(1));
+        assert_eq!(
+            contract.nft_tokens_for_owner_page(accounts(2), None, None).tokens[0].token_id,
+            "TOKEN_ID_1"
+        );
+    }
+
+    #[test]
+    fn test_migrate_tokens_in_batches() {
+        let mut context = get_context(accounts(0));
+        let mut contract = migrate_legacy_contract(&mut context, 5);
+        assert_eq!(
+            contract.get_migration_status(),
+            MigrationStatus { state_version: 0, remaining_tokens: U64(5) }
+        );
+
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        assert_eq!(contract.migrate_tokens(2), U64(3));
+        assert_eq!(contract.migrate_tokens(2), U64(1));
+        assert_eq!(contract.migrate_tokens(2), U64(0));
+        assert_eq!(
+            contract.get_migration_status(),
+            MigrationStatus { state_version: STATE_VERSION, remaining_tokens: U64(0) }
+        );
+
+        // 옮긴 순서와 관계없이 저장되어 있던 순서대로 민팅 순번을 받음
+        let ids: Vec<TokenId> = contract
+            .nft_tokens_for_owner_page(accounts(2), None, None)
+            .tokens
+            .into_iter()
+            .map(|token| token.token_id)
+            .collect();
+        assert_eq!(ids, vec!["TOKEN_ID_1", "TOKEN_ID_2", "TOKEN_ID_3", "TOKEN_ID_4", "TOKEN_ID_5"]);
+        assert_eq!(contract.nft_total_supply(), U128(5));
+
+        mint_tokens(&mut contract, &mut context, accounts(3), &["TOKEN_ID_6"]);
+        assert_eq!(contract.nft_token("TOKEN_ID_6".to_string()).unwrap().mint_seq, 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "Token migration is in progress")]
+    fn test_mint_during_migration() {
+        let mut context = get_context(accounts(0));
+        let mut contract = migrate_legacy_contract(&mut context, 3);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.migrate_tokens(1);
+        mint_tokens(&mut contract, &mut context, accounts(3), &["TOKEN_ID_6"]);
+    }
+
+    /// TEST for enumeration.rs (cursor)
+    // 토큰을 민팅하고 받는 계정이 전송할 수 있도록 스토리지 잔액을 넣어둠
+    fn mint_tokens(contract: &mut Contract, context: &mut VMContextBuilder, owner_id: AccountId, token_ids: &[&str]) {
+        deposit_storage(contract, context, owner_id.clone());
+        for (i, token_id) in token_ids.iter().enumerate() {
+            testing_env!(context
+                .storage_usage(env::storage_usage())
+                .attached_deposit(ATTACHED_VALUE)
+                .build());
+            let mut metadata = valid_token_metadata();
+            metadata.serial_number = format!("{:016}", contract.nft_total_supply().0 + i as u128);
+            contract.nft_mint(token_id.to_string(), metadata, owner_id.clone());
+        }
+    }
+
+    // 현재 호출자가 account_id 의 스토리지 잔액을 넣음. 첨부 금액은 원래대로 되돌림
+    fn deposit_storage(contract: &mut Contract, context: &mut VMContextBuilder, account_id: AccountId) {
+        let attached_deposit = context.context.attached_deposit;
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(ATTACHED_VALUE).build());
+        contract.storage_deposit(Some(account_id), None);
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(attached_deposit).build());
+    }
+
+    #[test]
+    fn test_nft_tokens_page() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2", "TOKEN_ID_3"]);
+
+        let page = contract.nft_tokens_page(None, Some(2));
+        let ids: Vec<TokenId> = page.tokens.iter().map(|token| token.token_id.clone()).collect();
+        assert_eq!(ids, vec!["TOKEN_ID_1", "TOKEN_ID_2"]);
+        assert_eq!(page.next_cursor, Some("TOKEN_ID_2".to_string()));
+
+        let page = contract.nft_tokens_page(page.next_cursor, Some(2));
+        assert_eq!(page.tokens.len(), 1);
+        assert_eq!(page.tokens[0].token_id, "TOKEN_ID_3");
+        assert_eq!(page.next_cursor, None);
+    }
+
+    #[test]
+    fn test_nft_tokens_for_owner_page_after_transfer() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2", "TOKEN_ID_3"]);
+
+        let page = contract.nft_tokens_for_owner_page(accounts(1), None, Some(1));
+        assert_eq!(page.next_cursor, Some("TOKEN_ID_1".to_string()));
+
+        // 첫 페이지를 받은 뒤 토큰이 이동해도 다음 페이지는 이어서 조회됨
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+
+        let page = contract.nft_tokens_for_owner_page(accounts(1), page.next_cursor, Some(5));
+        let ids: Vec<TokenId> = page.tokens.iter().map(|token| token.token_id.clone()).collect();
+        assert_eq!(ids, vec!["TOKEN_ID_2", "TOKEN_ID_3"]);
+        assert_eq!(page.next_cursor, None);
+
+        let page = contract.nft_tokens_for_owner_page(accounts(2), None, None);
+        assert_eq!(page.tokens[0].token_id, "TOKEN_ID_1");
+    }
+
+    #[test]
+    fn test_nft_latest_tokens_and_minted_between() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        context.block_timestamp(1_000);
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+        context.block_timestamp(2_000);
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_3"]);
+        context.block_timestamp(3_000);
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_4"]);
+
+        let latest: Vec<TokenId> = contract
+            .nft_latest_tokens(Some(2))
+            .into_iter()
+            .map(|token| token.token_id)
+            .collect();
+        assert_eq!(latest, vec!["TOKEN_ID_4", "TOKEN_ID_3"]);
+
+        let page = contract.nft_tokens_page_rev(Some("TOKEN_ID_3".to_string()), Some(1));
+        assert_eq!(page.tokens[0].token_id, "TOKEN_ID_2");
+        assert_eq!(page.tokens[0].mint_seq, 1);
+        assert_eq!(page.next_cursor, Some("TOKEN_ID_2".to_string()));
+
+        let page = contract.nft_tokens_minted_between(U64(1_000), U64(2_000), None, Some(2));
+        let ids: Vec<TokenId> = page.tokens.iter().map(|token| token.token_id.clone()).collect();
+        assert_eq!(ids, vec!["TOKEN_ID_1", "TOKEN_ID_2"]);
+
+        let page = contract.nft_tokens_minted_between(U64(1_000), U64(2_000), page.next_cursor, Some(2));
+        let ids: Vec<TokenId> = page.tokens.iter().map(|token| token.token_id.clone()).collect();
+        assert_eq!(ids, vec!["TOKEN_ID_3"]);
+        assert_eq!(page.next_cursor, None);
+
+        let page = contract.nft_tokens_minted_between(U64(1_500), U64(10_000), None, None);
+        assert_eq!(page.tokens.len(), 2);
+        assert!(contract.nft_tokens_minted_between(U64(3_001), U64(10_000), None, None).tokens.is_empty());
+    }
+
+    /// TEST for approval.rs
+    fn mint_and_approve(expires_at: Option<U64>) -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(500)
+            .build());
+        contract.nft_approve("TOKEN_ID_1".to_string(), accounts(2), None, expires_at);
+        (contract, context)
+    }
+
+    #[test]
+    fn test_nft_approve_with_expiry() {
+        let (contract, mut context) = mint_and_approve(Some(U64(1_000)));
+        assert!(contract.nft_is_approved("TOKEN_ID_1".to_string(), accounts(2), Some(0)));
+
+        testing_env!(context.block_timestamp(1_000).build());
+        assert!(!contract.nft_is_approved("TOKEN_ID_1".to_string(), accounts(2), None));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_nft_transfer_with_expired_approval() {
+        let (mut contract, mut context) = mint_and_approve(Some(U64(1_000)));
+
+        testing_env!(context
+            .attached_deposit(1)
+            .block_timestamp(2_000)
+            .predecessor_account_id(accounts(2))
+            .build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_1".to_string(), Some(0), None);
+    }
+
+    #[test]
+    fn test_prune_expired_approvals() {
+        let (mut contract, mut context) = mint_and_approve(Some(U64(1_000)));
+
+        testing_env!(context
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(600)
+            .build());
+        contract.nft_approve("TOKEN_ID_1".to_string(), accounts(3), None, None);
+
+        testing_env!(context
+            .attached_deposit(0)
+            .block_timestamp(2_000)
+            .predecessor_account_id(accounts(4))
+            .build());
+        assert_eq!(contract.prune_expired_approvals("TOKEN_ID_1".to_string()), 1);
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert!(!token.approved_account_ids.contains_key(&accounts(2)));
+        assert!(token.approved_account_ids.contains_key(&accounts(3)));
+        assert!(token.approval_expires_at.is_empty());
+        assert_eq!(contract.prune_expired_approvals("TOKEN_ID_1".to_string()), 0);
+    }
+
+    #[test]
+    fn test_nft_approve_all_operator_transfer() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_approve_all(accounts(3));
+        assert!(contract.nft_is_approved_for_all(accounts(1), accounts(3)));
+        assert!(!contract.nft_is_approved_for_all(accounts(1), accounts(4)));
+
+        // 전송을 시작하는 오퍼레이터가 이력 스토리지를 냄
+        deposit_storage(&mut contract, &mut context, accounts(3));
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(3))
+            .build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.nft_revoke_all_operators();
+        assert!(!contract.nft_is_approved_for_all(accounts(1), accounts(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Unauthorized")]
+    fn test_nft_operator_transfer_with_approval_id() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_approve_all(accounts(3));
+
+        // approval_id 를 지정하면 토큰 단위 승인이 필요함
+        testing_env!(context
+            .attached_deposit(1)
+            .predecessor_account_id(accounts(3))
+            .build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), Some(0), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_1 already has the maximum of 2 approvals")]
+    fn test_nft_approve_over_max_approvals() {
+        let (mut contract, mut context) = mint_and_approve(None);
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_max_approvals_per_token(2);
+
+        testing_env!(context.attached_deposit(ATTACHED_VALUE).build());
+        contract.nft_approve("TOKEN_ID_1".to_string(), accounts(3), None, None);
+        assert_eq!(contract.nft_approval_count("TOKEN_ID_1".to_string()), 2);
+
+        // 이미 승인된 계정을 다시 승인하는 것은 허용됨
+        contract.nft_approve("TOKEN_ID_1".to_string(), accounts(3), None, None);
+        contract.nft_approve("TOKEN_ID_1".to_string(), accounts(4), None, None);
+    }
+
+    /// TEST for nft_core.rs (nft_transfer_call, nft_resolve_transfer)
+    // 수신자 컨트랙트의 nft_on_transfer 결과를 흉내 내어 nft_resolve_transfer 를 실행
+    fn transfer_call_and_resolve(receiver_result: PromiseResult) -> (Contract, bool) {
+        let (mut contract, mut context) = mint_and_approve(None);
+
+        testing_env!(context.attached_deposit(1).build());
+        let _ = contract.nft_transfer_call(accounts(2), "TOKEN_ID_1".to_string(), None, None, "".to_string());
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+
+        testing_env!(
+            context.predecessor_account_id(accounts(0)).attached_deposit(0).build(),
+            near_sdk::VMConfig::test(),
+            near_sdk::RuntimeFeesConfig::test(),
+            Default::default(),
+            vec![receiver_result],
+        );
+        let mut approved_account_ids = HashMap::new();
+        approved_account_ids.insert(accounts(2), 0);
+        let transferred = contract.nft_resolve_transfer(
+            accounts(1),
+            accounts(2),
+            "TOKEN_ID_1".to_string(),
+            approved_account_ids,
+            Default::default(),
+            accounts(1),
+        );
+        (contract, transferred)
+    }
+
+    fn assert_returned_to_owner(contract: &Contract) {
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.owner_id, accounts(1));
+        assert!(token.approved_account_ids.contains_key(&accounts(2)));
+        assert_eq!(contract.nft_supply_for_owner(accounts(1)), U128(1));
+        assert_eq!(contract.nft_supply_for_owner(accounts(2)), U128(0));
+        assert_eq!(contract.nft_tokens_for_owner_page(accounts(1), None, None).tokens.len(), 1);
+    }
+
+    #[test]
+    fn test_nft_resolve_transfer_receiver_keeps_token() {
+        let (contract, transferred) = transfer_call_and_resolve(PromiseResult::Successful(b"false".to_vec()));
+        assert!(transferred);
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.owner_id, accounts(2));
+        assert!(token.approved_account_ids.is_empty());
+    }
+
+    #[test]
+    fn test_nft_resolve_transfer_receiver_returns_token() {
+        let (contract, transferred) = transfer_call_and_resolve(PromiseResult::Successful(b"true".to_vec()));
+        assert!(!transferred);
+        assert_returned_to_owner(&contract);
+    }
+
+    #[test]
+    fn test_nft_resolve_transfer_malformed_return_value() {
+        let (contract, transferred) = transfer_call_and_resolve(PromiseResult::Successful(b"\"yes\"".to_vec()));
+        assert!(!transferred);
+        assert_returned_to_owner(&contract);
+    }
+
+    #[test]
+    fn test_nft_resolve_transfer_receiver_panics() {
+        let (contract, transferred) = transfer_call_and_resolve(PromiseResult::Failed);
+        assert!(!transferred);
+        assert_returned_to_owner(&contract);
+    }
+
+    #[test]
+    #[should_panic(expected = "Attach at least 65000000000000 gas to nft_transfer_call")]
+    fn test_nft_transfer_call_not_enough_gas() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context
+            .attached_deposit(1)
+            .prepaid_gas(near_sdk::Gas(50_000_000_000_000))
+            .build());
+        let _ = contract.nft_transfer_call(accounts(2), "TOKEN_ID_1".to_string(), None, None, "".to_string());
+    }
+
+    #[test]
+    fn test_nft_transfer_charges_prepaid_storage() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+        let before = contract.storage_balance_of(accounts(1)).unwrap();
+        assert_eq!(before.total.0 - before.available.0, contract.storage_balance_bounds().min.0);
+
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+
+        // 늘어난 소유 이력 스토리지만큼 발신자의 스토리지 잔액이 줄어듦
+        let after = contract.storage_balance_of(accounts(1)).unwrap();
+        assert!(after.available.0 < before.available.0);
+        assert!(contract.storage_balance_of(accounts(2)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Account bob needs")]
+    fn test_nft_transfer_without_enough_prepaid_storage() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        // 사용 가능한 스토리지 잔액을 모두 돌려받으면 전송할 수 없음
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(1).build());
+        let balance = contract.storage_withdraw(None);
+        assert_eq!(balance.available, U128(0));
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+    }
+
+    /// TEST for drops.rs
+    fn mint_in_locked_drop() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).block_timestamp(100).build());
+        contract.create_drop(
+            "DROP_1".to_string(),
+            DropConfig { transfer_unlock_at: Some(U64(1_000)), max_mints_per_account: None },
+        );
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_mint_in_drop(
+            "TOKEN_ID_1".to_string(),
+            valid_token_metadata(),
+            accounts(1),
+            "DROP_1".to_string(),
+        );
+        deposit_storage(&mut contract, &mut context, accounts(1));
+        (contract, context)
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_1 is locked for transfers until 1000")]
+    fn test_nft_transfer_locked_drop() {
+        let (mut contract, mut context) = mint_in_locked_drop();
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.drop_id, Some("DROP_1".to_string()));
+        assert_eq!(token.transfer_locked_until, Some(U64(1_000)));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+    }
+
+    #[test]
+    fn test_nft_transfer_after_drop_unlock() {
+        let (mut contract, mut context) = mint_in_locked_drop();
+
+        testing_env!(context.attached_deposit(1).block_timestamp(1_000).build());
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().transfer_locked_until, None);
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+    }
+
+    #[test]
+    fn test_clear_drop_transfer_lock() {
+        let (mut contract, mut context) = mint_in_locked_drop();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.clear_drop_transfer_lock("DROP_1".to_string());
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+    }
+
+    /// TEST for escrow.rs
+    fn mint_and_offer() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(100)
+            .build());
+        contract.nft_offer_transfer("TOKEN_ID_1".to_string(), accounts(2), U64(1_000));
+        (contract, context)
+    }
+
+    #[test]
+    fn test_nft_accept_transfer() {
+        let (mut contract, mut context) = mint_and_offer();
+        assert_eq!(contract.nft_transfer_offer("TOKEN_ID_1".to_string()).unwrap().receiver_id, accounts(2));
+
+        testing_env!(context
+            .attached_deposit(ATTACHED_VALUE)
+            .predecessor_account_id(accounts(2))
+            .build());
+        contract.nft_accept_transfer("TOKEN_ID_1".to_string());
+
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+        assert!(contract.nft_transfer_offer("TOKEN_ID_1".to_string()).is_none());
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"nft_transfer_offer_accept","data":[{"token_id":"TOKEN_ID_1","sender_id":"bob","receiver_id":"charlie","expires_at":"1000"}]}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_1 has a pending transfer offer")]
+    fn test_nft_transfer_with_pending_offer() {
+        let (mut contract, mut context) = mint_and_offer();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_1".to_string(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "The transfer offer has expired")]
+    fn test_nft_accept_expired_transfer_offer() {
+        let (mut contract, mut context) = mint_and_offer();
+
+        testing_env!(context
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(1_000)
+            .predecessor_account_id(accounts(2))
+            .build());
+        contract.nft_accept_transfer("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    fn test_nft_cancel_transfer_offer() {
+        let (mut contract, mut context) = mint_and_offer();
+
+        testing_env!(context.attached_deposit(1).block_timestamp(2_000).build());
+        contract.nft_cancel_transfer_offer("TOKEN_ID_1".to_string());
+        assert!(contract.nft_transfer_offer("TOKEN_ID_1".to_string()).is_none());
+
+        // 취소 후에는 다시 자유롭게 전송할 수 있음
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(3));
+    }
+
+    /// TEST for moderation.rs
+    fn contract_with_moderator() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.add_moderator(accounts(4));
+        (contract, context)
+    }
+
+    #[test]
+    fn test_block_account() {
+        let (mut contract, mut context) = contract_with_moderator();
+
+        testing_env!(context.predecessor_account_id(accounts(4)).build());
+        contract.block_account(accounts(3), BlockReason::LegalRequest, Some("case-1".to_string()));
+        assert!(contract.is_account_blocked(accounts(3)));
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"account_block","data":[{"account_id":"danny","reason":"legal_request","memo":"case-1","moderator_id":"eugene"}]}"#
+        );
+
+        let blocked = contract.get_blocked_accounts(None, None);
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].account_id, accounts(3));
+        assert_eq!(blocked[0].blocked.reason, BlockReason::LegalRequest);
+
+        contract.unblock_account(accounts(3), None);
+        assert!(!contract.is_account_blocked(accounts(3)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only a moderator can call this method")]
+    fn test_block_account_not_moderator() {
+        let (mut contract, mut context) = contract_with_moderator();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).build());
+        contract.block_account(accounts(3), BlockReason::Fraud, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account danny is blocked (LegalRequest)")]
+    fn test_nft_transfer_to_blocked_account() {
+        let (mut contract, mut context) = contract_with_moderator();
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context.attached_deposit(1).predecessor_account_id(accounts(4)).build());
+        contract.block_account(accounts(3), BlockReason::LegalRequest, None);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_1".to_string(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account danny is blocked (Sanctions)")]
+    fn test_nft_mint_to_blocked_account() {
+        let (mut contract, mut context) = contract_with_moderator();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.block_account(accounts(3), BlockReason::Sanctions, None);
+        mint_tokens(&mut contract, &mut context, accounts(3), &["TOKEN_ID_1"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_1 is frozen: reported stolen")]
+    fn test_nft_transfer_frozen_token() {
+        let (mut contract, mut context) = contract_with_moderator();
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context.attached_deposit(1).predecessor_account_id(accounts(4)).build());
+        contract.freeze_token("TOKEN_ID_1".to_string(), "reported stolen".to_string());
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert!(token.frozen);
+        assert_eq!(token.frozen_reason, Some("reported stolen".to_string()));
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+    }
+
+    #[test]
+    fn test_unfreeze_token() {
+        let (mut contract, mut context) = contract_with_moderator();
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context.attached_deposit(1).predecessor_account_id(accounts(4)).build());
+        contract.freeze_token("TOKEN_ID_1".to_string(), "dispute".to_string());
+        contract.unfreeze_token("TOKEN_ID_1".to_string());
+        assert!(!contract.nft_token("TOKEN_ID_1".to_string()).unwrap().frozen);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(2));
+    }
+
+    /// TEST for recovery.rs
+    fn request_recovery_of_lost_token() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1"]);
+
+        testing_env!(context
+            .predecessor_account_id(accounts(1))
+            .attached_deposit(1)
+            .block_timestamp(100)
+            .build());
+        contract.set_recovery_delay(U64(1_000));
+        contract.request_recovery("TOKEN_ID_1".to_string(), accounts(3), Base64VecU8(vec![7; 32]));
+        (contract, context)
+    }
+
+    #[test]
+    fn test_execute_recovery() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+        assert!(near_sdk::test_utils::get_logs().last().unwrap().contains(r#""event":"recovery_request""#));
+
+        testing_env!(context.block_timestamp(1_100).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.owner_id, accounts(3));
+        assert!(near_sdk::test_utils::get_logs().last().unwrap().contains(r#""event":"recovery_execute""#));
+
+        let records = contract.get_recovery_records("TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].previous_owner_id, accounts(2));
+        assert_eq!(records[0].status, RecoveryStatus::Executed);
+        assert_eq!(records[0].resolved_at, Some(U64(1_100)));
+    }
+
+    #[test]
+    #[should_panic(expected = "The recovery can be executed after 1100")]
+    fn test_execute_recovery_before_delay() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.block_timestamp(1_099).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    fn test_veto_recovery() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(500).build());
+        contract.veto_recovery("TOKEN_ID_1".to_string());
+        assert!(near_sdk::test_utils::get_logs().last().unwrap().contains(r#""event":"recovery_veto""#));
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.owner_id, accounts(2));
+
+        // 거부된 요청은 기록에 남고, 새 요청을 할 수 있음
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.request_recovery("TOKEN_ID_1".to_string(), accounts(3), Base64VecU8(vec![8; 32]));
+        let records = contract.get_recovery_records("TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].status, RecoveryStatus::Vetoed);
+        assert_eq!(records[1].status, RecoveryStatus::Pending);
+    }
+
+    #[test]
+    #[should_panic(expected = "No pending recovery for the token")]
+    fn test_execute_vetoed_recovery() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(500).build());
+        contract.veto_recovery("TOKEN_ID_1".to_string());
+
+        testing_env!(context.predecessor_account_id(accounts(1)).block_timestamp(1_100).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "The veto period of the recovery has ended")]
+    fn test_veto_recovery_after_delay() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(1_100).build());
+        contract.veto_recovery("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the current token owner can veto the recovery")]
+    fn test_veto_recovery_not_token_owner() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.predecessor_account_id(accounts(3)).block_timestamp(500).build());
+        contract.veto_recovery("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "The token owner changed after the recovery was requested")]
+    fn test_execute_recovery_after_owner_changed() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(500).attached_deposit(1).build());
+        contract.nft_transfer(accounts(4), "TOKEN_ID_1".to_string(), None, None);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).block_timestamp(1_100).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+    }
+
+    #[test]
+    fn test_execute_recovery_of_frozen_token_from_blocked_owner() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+
+        // 분쟁 중인 토큰은 동결되고 이전 소유자는 차단된 상태에서도 복구할 수 있음
+        contract.freeze_token("TOKEN_ID_1".to_string(), "stolen".to_string());
+        contract.block_account(accounts(2), BlockReason::Fraud, None);
+
+        testing_env!(context.block_timestamp(1_100).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(3));
+    }
+
+    #[test]
+    fn test_execute_recovery_of_drop_locked_token() {
+        let (mut contract, mut context) = mint_in_locked_drop();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_recovery_delay(U64(100));
+        contract.request_recovery("TOKEN_ID_1".to_string(), accounts(3), Base64VecU8(vec![7; 32]));
+
+        // 드롭의 전송 잠금(1000)이 풀리기 전에도 복구할 수 있음
+        testing_env!(context.block_timestamp(200).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Account danny is blocked (Fraud)")]
+    fn test_execute_recovery_to_blocked_account() {
+        let (mut contract, mut context) = request_recovery_of_lost_token();
+        contract.block_account(accounts(3), BlockReason::Fraud, None);
+
+        testing_env!(context.block_timestamp(1_100).attached_deposit(ATTACHED_VALUE).build());
+        contract.execute_recovery("TOKEN_ID_1".to_string());
+    }
+
+    /// TEST for provenance.rs
+    #[test]
+    fn test_nft_token_history() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.block_timestamp(100).block_index(10).build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context.attached_deposit(1).block_timestamp(200).block_index(20).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, None);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(300).block_index(30).build());
+        contract.nft_burn("TOKEN_ID_1".to_string(), None);
+
+        let history = contract.nft_token_history("TOKEN_ID_1".to_string(), None, None);
+        let summary: Vec<(AccountId, ProvenanceKind, u64, u64)> = history
+            .iter()
+            .map(|entry| (entry.owner_id.clone(), entry.kind, entry.timestamp.0, entry.block_height.0))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (accounts(1), ProvenanceKind::Mint, 100, 10),
+                (accounts(2), ProvenanceKind::Transfer, 200, 20),
+                (accounts(2), ProvenanceKind::Burn, 300, 30),
+            ]
+        );
+        assert!(history.iter().all(|entry| entry.price.is_none()));
+
+        let page = contract.nft_token_history("TOKEN_ID_1".to_string(), Some(U128(1)), Some(1));
+        assert_eq!(page.len(), 1);
+        assert_eq!(page[0].kind, ProvenanceKind::Transfer);
+    }
+
+    #[test]
+    fn test_nft_token_history_returned_token() {
+        let (contract, transferred) = transfer_call_and_resolve(PromiseResult::Successful(b"true".to_vec()));
+        assert!(!transferred);
+
+        let kinds: Vec<ProvenanceKind> = contract
+            .nft_token_history("TOKEN_ID_1".to_string(), None, None)
+            .iter()
+            .map(|entry| entry.kind)
+            .collect();
+        assert_eq!(kinds, vec![ProvenanceKind::Mint, ProvenanceKind::Transfer, ProvenanceKind::Return]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner or a minter can call this method")]
+    fn test_nft_mint_not_minter() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(ATTACHED_VALUE).build());
+        contract.nft_mint("TOKEN_ID_1".to_string(), valid_token_metadata(), accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the owner or a minter can call this method")]
+    fn test_nft_mint_in_drop_removed_minter() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.attached_deposit(1).build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        contract.add_minter(accounts(2));
+        assert_eq!(contract.get_minters(), vec![accounts(2)]);
+        contract.remove_minter(accounts(2));
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(ATTACHED_VALUE).build());
+        contract.nft_mint_in_drop("TOKEN_ID_1".to_string(), valid_token_metadata(), accounts(2), "drop".to_string());
+    }
+
+    #[test]
+    fn test_nft_burn() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(1).build());
+        contract.nft_burn("TOKEN_ID_1".to_string(), Some("bye".to_string()));
+        // 해제된 토큰 스토리지는 민팅한 쪽이 냈으므로 소각한 계정에 환불하지 않음
+        assert!(near_sdk::test_utils::get_created_receipts().is_empty());
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"nep171","version":"1.1.0","event":"nft_burn","data":[{"owner_id":"bob","token_ids":["TOKEN_ID_1"],"memo":"bye"}]}"#
+        );
+
+        assert!(contract.nft_token("TOKEN_ID_1".to_string()).is_none());
+        assert_eq!(contract.nft_total_supply(), U128(1));
+        assert_eq!(contract.nft_supply_for_owner(accounts(1)), U128(1));
+        let ids: Vec<TokenId> = contract.nft_tokens_page(None, None).tokens.into_iter().map(|token| token.token_id).collect();
+        assert_eq!(ids, vec!["TOKEN_ID_2"]);
+        assert_eq!(contract.nft_tokens_for_owner_page(accounts(1), None, None).tokens.len(), 1);
+
+        // 소각된 토큰을 cursor 로 사용해도 이어서 조회할 수 있음
+        let page = contract.nft_tokens_page(Some("TOKEN_ID_1".to_string()), None);
+        assert_eq!(page.tokens.len(), 1);
+    }
+
+    #[test]
+    fn test_nft_mint_and_transfer_events() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+        assert!(near_sdk::test_utils::get_logs().iter().any(|log| log
+            == r#"EVENT_JSON:{"standard":"nep171","version":"1.1.0","event":"nft_mint","data":[{"owner_id":"bob","token_ids":["TOKEN_ID_1"]}]}"#));
+
+        testing_env!(context.storage_usage(env::storage_usage()).attached_deposit(1).build());
+        contract.nft_transfer(accounts(2), "TOKEN_ID_1".to_string(), None, Some("gift".to_string()));
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"nep171","version":"1.1.0","event":"nft_transfer","data":[{"old_owner_id":"bob","new_owner_id":"charlie","token_ids":["TOKEN_ID_1"],"memo":"gift"}]}"#
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the token owner can burn the token")]
+    fn test_nft_burn_not_owner() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+        contract.nft_burn("TOKEN_ID_1".to_string(), None);
+    }
+
+    /// TEST for limits.rs
+    #[test]
+    #[should_panic(expected = "The maximum supply of 2 tokens has been minted")]
+    fn test_nft_mint_over_max_supply() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_mint_limits(MintLimits { max_supply: Some(U64(2)), max_held_per_streamer: None });
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+        assert_eq!(contract.get_remaining_supply(), Some(U64(0)));
+
+        // 소각해도 발행 가능 수량은 늘어나지 않음
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_burn("TOKEN_ID_1".to_string(), None);
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_3"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account charlie already minted the maximum of 2 tokens in drop DROP_1")]
+    fn test_nft_mint_in_drop_over_account_limit() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.create_drop(
+            "DROP_1".to_string(),
+            DropConfig { transfer_unlock_at: None, max_mints_per_account: Some(2) },
+        );
+        assert_eq!(contract.get_remaining_drop_mints("DROP_1".to_string(), accounts(2)), Some(2));
+
+        for (i, token_id) in ["TOKEN_ID_1", "TOKEN_ID_2", "TOKEN_ID_3"].iter().enumerate() {
+            testing_env!(context
+                .storage_usage(env::storage_usage())
+                .attached_deposit(ATTACHED_VALUE)
+                .build());
+            let mut metadata = valid_token_metadata();
+            metadata.serial_number = format!("{:016}", i);
+            contract.nft_mint_in_drop(token_id.to_string(), metadata, accounts(2), "DROP_1".to_string());
+            assert_eq!(contract.get_remaining_drop_mints("DROP_1".to_string(), accounts(2)), Some(1 - i as u32));
+        }
+    }
+
+    #[test]
+    fn test_max_held_per_streamer() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_mint_limits(MintLimits { max_supply: None, max_held_per_streamer: Some(1) });
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_2"]);
+
+        let streamer_name = valid_token_metadata().streamer_name;
+        assert_eq!(contract.get_remaining_streamer_allowance(accounts(1), streamer_name.clone()), Some(0));
+        assert_eq!(contract.get_remaining_streamer_allowance(accounts(3), streamer_name.clone()), Some(1));
+
+        // 보유 한도에 도달한 계정에는 전송할 수 없고, 내보낸 뒤에는 다시 받을 수 있음
+        testing_env!(context.attached_deposit(1).build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(contract.get_remaining_streamer_allowance(accounts(1), streamer_name), Some(1));
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_3"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account bob already holds the maximum of 1 tokens of")]
+    fn test_nft_transfer_over_max_held_per_streamer() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_mint_limits(MintLimits { max_supply: None, max_held_per_streamer: Some(1) });
+        mint_tokens(&mut contract, &mut context, accounts(1), &["TOKEN_ID_1"]);
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_2"]);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+        contract.nft_transfer(accounts(1), "TOKEN_ID_2".to_string(), None, None);
+    }
+
+    /// TEST for presale.rs
+    // 리프 목록으로 머클 트리를 만들고 (루트, 각 리프의 증명)을 반환
+    fn build_merkle_tree(leaves: Vec<Vec<u8>>) -> (Vec<u8>, Vec<Vec<Base64VecU8>>) {
+        let mut proofs: Vec<Vec<Base64VecU8>> = vec![vec![]; leaves.len()];
+        // 각 리프가 현재 층에서 몇 번째 노드인지
+        let mut positions: Vec<usize> = (0..leaves.len()).collect();
+        let mut level = leaves;
+
+        while level.len() > 1 {
+            let mut next = vec![];
+            for pair in level.chunks(2) {
+                // 짝이 없는 마지막 노드는 그대로 다음 층으로 올림
+                next.push(if pair.len() == 2 { merkle_parent(&pair[0], &pair[1]) } else { pair[0].clone() });
+            }
+            for (proof, position) in proofs.iter_mut().zip(positions.iter_mut()) {
+                let sibling = *position ^ 1;
+                if sibling < level.len() {
+                    proof.push(Base64VecU8(level[sibling].clone()));
+                }
+                *position /= 2;
+            }
+            level = next;
+        }
+        (level[0].clone(), proofs)
+    }
+
+    fn contract_with_presale() -> (Contract, VMContextBuilder, Vec<Vec<Base64VecU8>>) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        let allowlist = [(accounts(2), 2), (accounts(3), 1), (accounts(4), 5)];
+        let (root, proofs) = build_merkle_tree(
+            allowlist.iter().map(|(account_id, allocation)| presale_leaf(account_id, *allocation)).collect(),
+        );
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_sale_phase(
+            "SUBSCRIBERS".to_string(),
+            SalePhase {
+                merkle_root: Base64VecU8(root),
+                starts_at: U64(100),
+                ends_at: U64(1_000),
+                drop_id: None,
+                metadata: valid_token_metadata(),
+            },
+        );
+        (contract, context, proofs)
+    }
+
+    fn presale_mint(
+        contract: &mut Contract,
+        context: &mut VMContextBuilder,
+        account_id: AccountId,
+        allocation: u32,
+        proof: Vec<Base64VecU8>,
+    ) -> TokenId {
+        testing_env!(context
+            .predecessor_account_id(account_id)
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(500)
+            .build());
+        contract.nft_presale_mint("SUBSCRIBERS".to_string(), allocation, proof)
+    }
+
+    #[test]
+    fn test_nft_presale_mint() {
+        let (mut contract, mut context, proofs) = contract_with_presale();
+
+        presale_mint(&mut contract, &mut context, accounts(2), 2, proofs[0].clone());
+        presale_mint(&mut contract, &mut context, accounts(2), 2, proofs[0].clone());
+        let token_id = presale_mint(&mut contract, &mut context, accounts(4), 5, proofs[2].clone());
+
+        assert_eq!(token_id, "SUBSCRIBERS-2");
+        assert_eq!(contract.get_presale_claimed("SUBSCRIBERS".to_string(), accounts(2)), 2);
+        assert_eq!(contract.get_presale_claimed("SUBSCRIBERS".to_string(), accounts(4)), 1);
+        assert_eq!(contract.nft_supply_for_owner(accounts(2)), U128(2));
+        let token = contract.nft_token(token_id).unwrap();
+        assert_eq!(token.owner_id, accounts(4));
+        assert_eq!(token.metadata.title, valid_token_metadata().title);
+    }
+
+    #[test]
+    #[should_panic(expected = "Account charlie already claimed its allocation of 2 tokens")]
+    fn test_nft_presale_mint_over_allocation() {
+        let (mut contract, mut context, proofs) = contract_with_presale();
+
+        presale_mint(&mut contract, &mut context, accounts(2), 2, proofs[0].clone());
+        presale_mint(&mut contract, &mut context, accounts(2), 2, proofs[0].clone());
+        presale_mint(&mut contract, &mut context, accounts(2), 2, proofs[0].clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid allowlist proof")]
+    fn test_nft_presale_mint_inflated_allocation() {
+        let (mut contract, mut context, proofs) = contract_with_presale();
+
+        presale_mint(&mut contract, &mut context, accounts(3), 3, proofs[1].clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid allowlist proof")]
+    fn test_nft_presale_mint_someone_elses_proof() {
+        let (mut contract, mut context, proofs) = contract_with_presale();
+
+        presale_mint(&mut contract, &mut context, accounts(5), 2, proofs[0].clone());
+    }
+
+    #[test]
+    #[should_panic(expected = "Sale phase SUBSCRIBERS is not active")]
+    fn test_nft_presale_mint_after_phase_end() {
+        let (mut contract, mut context, proofs) = contract_with_presale();
+
+        testing_env!(context
+            .predecessor_account_id(accounts(3))
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(1_000)
+            .build());
+        contract.nft_presale_mint("SUBSCRIBERS".to_string(), 1, proofs[1].clone());
+    }
+
+    /// TEST for vouchers.rs
+    fn voucher_keypair(seed: u8) -> (ed25519_dalek::Keypair, PublicKey) {
+        let secret = ed25519_dalek::SecretKey::from_bytes(&[seed; 32]).unwrap();
+        let public = ed25519_dalek::PublicKey::from(&secret);
+        let public_key = PublicKey::try_from([vec![0], public.to_bytes().to_vec()].concat()).unwrap();
+        (ed25519_dalek::Keypair { secret, public }, public_key)
+    }
+
+    fn sign_voucher(keypair: &ed25519_dalek::Keypair, voucher: &MintVoucher) -> Base64VecU8 {
+        use ed25519_dalek::Signer;
+        Base64VecU8(keypair.sign(&voucher.signing_message()).to_bytes().to_vec())
+    }
+
+    fn donor_voucher(token_id: &str, nonce: u64) -> MintVoucher {
+        MintVoucher {
+            token_id: token_id.to_string(),
+            metadata: valid_token_metadata(),
+            receiver_id: accounts(3),
+            price: U128(1_000),
+            expires_at: U64(1_000),
+            nonce: U64(nonce),
+        }
+    }
+
+    fn contract_with_voucher_signer() -> (Contract, VMContextBuilder, ed25519_dalek::Keypair) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        let (keypair, public_key) = voucher_keypair(1);
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_voucher_signer(public_key);
+
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(500)
+            .build());
+        (contract, context, keypair)
+    }
+
+    #[test]
+    fn test_nft_redeem_voucher() {
+        let (mut contract, _, keypair) = contract_with_voucher_signer();
+
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+
+        assert_eq!(contract.nft_token("TOKEN_ID_1".to_string()).unwrap().owner_id, accounts(3));
+        let history = contract.nft_token_history("TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(history[0].kind, ProvenanceKind::Mint);
+        assert_eq!(history[0].price, Some(U128(1_000)));
+        assert!(contract.is_voucher_nonce_used(U64(7)));
+        assert!(!contract.is_voucher_nonce_used(U64(8)));
+    }
+
+    #[test]
+    #[should_panic(expected = "Voucher nonce 7 has already been used")]
+    fn test_nft_redeem_voucher_replayed_nonce() {
+        let (mut contract, mut context, keypair) = contract_with_voucher_signer();
+
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+
+        testing_env!(context.storage_usage(env::storage_usage()).build());
+        let voucher = donor_voucher("TOKEN_ID_2", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid voucher signature")]
+    fn test_nft_redeem_voucher_tampered() {
+        let (mut contract, _, keypair) = contract_with_voucher_signer();
+
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        let mut tampered = voucher;
+        tampered.price = U128(0);
+        contract.nft_redeem_voucher(tampered, signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid voucher signature")]
+    fn test_nft_redeem_voucher_after_key_rotation() {
+        let (mut contract, mut context, old_keypair) = contract_with_voucher_signer();
+
+        let (_, new_public_key) = voucher_keypair(2);
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        contract.set_voucher_signer(new_public_key);
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(ATTACHED_VALUE).build());
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&old_keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "The voucher has expired")]
+    fn test_nft_redeem_expired_voucher() {
+        let (mut contract, mut context, keypair) = contract_with_voucher_signer();
+
+        testing_env!(context.block_timestamp(1_000).build());
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "to cover the price and storage")]
+    fn test_nft_redeem_voucher_without_price() {
+        let (mut contract, mut context, keypair) = contract_with_voucher_signer();
+
+        testing_env!(context.attached_deposit(999).build());
+        let voucher = donor_voucher("TOKEN_ID_1", 7);
+        let signature = sign_voucher(&keypair, &voucher);
+        contract.nft_redeem_voucher(voucher, signature);
+    }
+
+    /// TEST for claims.rs
+    const CLAIM_STORAGE_DEPOSIT: Balance = 30_000_000_000_000_000_000_000;
+
+    // 클레임 코드 비밀 키로 받는 계정에 서명
+    fn sign_claim(keypair: &ed25519_dalek::Keypair, receiver_id: &AccountId) -> Base64VecU8 {
+        use ed25519_dalek::Signer;
+        Base64VecU8(keypair.sign(&claim_signing_message(receiver_id)).to_bytes().to_vec())
+    }
+
+    fn claim_with_code(contract: &mut Contract, seed: u8, receiver_id: AccountId) -> TokenId {
+        let (keypair, public_key) = voucher_keypair(seed);
+        let signature = sign_claim(&keypair, &receiver_id);
+        contract.claim(public_key, receiver_id, signature)
+    }
+
+    fn claim_public_key(seed: u8) -> PublicKey {
+        voucher_keypair(seed).1
+    }
+
+    fn contract_with_claim_codes(expires_at: Option<U64>) -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.add_claim_template("FANMEET".to_string(), valid_token_metadata());
+        contract.add_claim_codes(
+            "FANMEET".to_string(),
+            vec![claim_public_key(11), claim_public_key(12), claim_public_key(13)],
+            expires_at,
+            U128(CLAIM_STORAGE_DEPOSIT),
+        );
+
+        testing_env!(context
+            .predecessor_account_id(accounts(3))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(0)
+            .block_timestamp(500)
+            .build());
+        (contract, context)
+    }
+
+    #[test]
+    fn test_claim() {
+        let (mut contract, _) = contract_with_claim_codes(None);
+
+        let token_id = claim_with_code(&mut contract, 11, accounts(2));
+        assert_eq!(token_id, "FANMEET-0");
+        assert_eq!(contract.nft_token(token_id).unwrap().owner_id, accounts(2));
+        assert!(contract.get_claim_code(claim_public_key(11)).is_none());
+        assert!(contract.get_claim_code(claim_public_key(12)).is_some());
+
+        let token_id = claim_with_code(&mut contract, 12, accounts(4));
+        assert_eq!(token_id, "FANMEET-1");
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid or already used claim code")]
+    fn test_claim_twice() {
+        let (mut contract, _) = contract_with_claim_codes(None);
+
+        claim_with_code(&mut contract, 11, accounts(2));
+        claim_with_code(&mut contract, 11, accounts(4));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid claim signature")]
+    fn test_claim_front_run_receiver() {
+        let (mut contract, _) = contract_with_claim_codes(None);
+
+        // 대기 중인 트랜잭션의 공개 키와 서명을 다른 받는 계정으로 재사용할 수 없음
+        let (keypair, public_key) = voucher_keypair(11);
+        let signature = sign_claim(&keypair, &accounts(2));
+        contract.claim(public_key, accounts(5), signature);
+    }
+
+    #[test]
+    #[should_panic(expected = "The claim code has expired")]
+    fn test_claim_expired_code() {
+        let (mut contract, mut context) = contract_with_claim_codes(Some(U64(1_000)));
+
+        testing_env!(context.block_timestamp(1_000).build());
+        claim_with_code(&mut contract, 11, accounts(2));
+    }
+
+    #[test]
+    fn test_revoke_claim_codes() {
+        let (mut contract, mut context) = contract_with_claim_codes(None);
+        claim_with_code(&mut contract, 11, accounts(2));
+
+        testing_env!(context.predecessor_account_id(accounts(1)).attached_deposit(1).build());
+        let revoked = contract.revoke_claim_codes(vec![claim_public_key(11), claim_public_key(12), claim_public_key(13)]);
+        assert_eq!(revoked, 2);
+        assert!(contract.get_claim_code(claim_public_key(13)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "to cover the claim codes and their storage deposits")]
+    fn test_add_claim_codes_without_deposit() {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(ATTACHED_VALUE).build());
+        contract.add_claim_template("FANMEET".to_string(), valid_token_metadata());
+        contract.add_claim_codes(
+            "FANMEET".to_string(),
+            vec![claim_public_key(11)],
+            None,
+            U128(ATTACHED_VALUE),
+        );
+    }
+
+    /// TEST for mystery_box.rs
+    fn seed_rolling(roll: u64) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        seed[..8].copy_from_slice(&roll.to_le_bytes());
+        seed
+    }
+
+    fn rarity_tier(name: &str, weight: u32, max_supply: u32) -> RarityTier {
+        let mut metadata = valid_token_metadata();
+        metadata.title = format!("{} card", name);
+        RarityTier { name: name.to_string(), weight, max_supply, minted: 0, metadata }
+    }
+
+    fn contract_with_mystery_box() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.create_mystery_box(
+            "GACHA".to_string(),
+            U128(1_000),
+            vec![rarity_tier("common", 70, 2), rarity_tier("rare", 25, 1), rarity_tier("legendary", 5, 1)],
+        );
+        (contract, context)
+    }
+
+    fn open_mystery_box(contract: &mut Contract, context: &mut VMContextBuilder, roll: u64) -> TokenId {
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .random_seed(seed_rolling(roll))
+            .build());
+        contract.nft_open_mystery_box("GACHA".to_string())
+    }
+
+    #[test]
+    fn test_nft_open_mystery_box() {
+        let (mut contract, mut context) = contract_with_mystery_box();
+
+        let token_id = open_mystery_box(&mut contract, &mut context, 97);
+        assert_eq!(token_id, "GACHA-0");
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"mystery_box_reveal","data":[{"box_id":"GACHA","token_id":"GACHA-0","owner_id":"charlie","tier":"legendary"}]}"#
+        );
+        assert_eq!(contract.nft_token_history(token_id.clone(), None, None)[0].price, Some(U128(1_000)));
+        let token = contract.nft_token(token_id).unwrap();
+        assert_eq!(token.owner_id, accounts(2));
+        assert_eq!(token.metadata.title, "legendary card");
+
+        open_mystery_box(&mut contract, &mut context, 80);
+        let supply = contract.get_mystery_box_supply("GACHA".to_string());
+        assert_eq!(
+            supply,
+            vec![
+                RarityTierSupply { name: "common".to_string(), remaining: 2 },
+                RarityTierSupply { name: "rare".to_string(), remaining: 0 },
+                RarityTierSupply { name: "legendary".to_string(), remaining: 0 },
+            ]
+        );
+
+        // 다 팔린 등급은 제외하고 뽑음
+        let token_id = open_mystery_box(&mut contract, &mut context, 97);
+        assert_eq!(contract.nft_token(token_id).unwrap().metadata.title, "common card");
+    }
+
+    #[test]
+    #[should_panic(expected = "The mystery box is sold out")]
+    fn test_nft_open_sold_out_mystery_box() {
+        let (mut contract, mut context) = contract_with_mystery_box();
+
+        for roll in 0..5 {
+            open_mystery_box(&mut contract, &mut context, roll);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "to cover the price and storage")]
+    fn test_nft_open_mystery_box_without_price() {
+        let (mut contract, mut context) = contract_with_mystery_box();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(1).build());
+        contract.nft_open_mystery_box("GACHA".to_string());
+    }
+
+    /// TEST for reveal.rs
+    fn final_metadata(index: u64) -> TokenMetadata {
+        let mut metadata = valid_token_metadata();
+        metadata.title = format!("Final art #{}", index);
+        metadata.serial_number = format!("{:016}", index);
+        metadata
+    }
+
+    fn reveal_leaf(token_id: &str, metadata: &TokenMetadata) -> Vec<u8> {
+        env::sha256(&(token_id.to_string(), metadata).try_to_vec().unwrap())
+    }
+
+    // SEASON_1-0..2 를 대체 메타데이터로 민팅하고 각 토큰의 공개 항목을 반환
+    fn mint_unrevealed_series() -> (Contract, VMContextBuilder, Vec<RevealEntry>) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        let token_ids = ["SEASON_1-0", "SEASON_1-1", "SEASON_1-2"];
+        let finals: Vec<TokenMetadata> = (1..=3).map(final_metadata).collect();
+        let (root, proofs) = build_merkle_tree(
+            token_ids.iter().zip(&finals).map(|(token_id, metadata)| reveal_leaf(token_id, metadata)).collect(),
+        );
+
+        let mut placeholder = valid_token_metadata();
+        placeholder.title = "Coming soon".to_string();
+        testing_env!(context.attached_deposit(1).build());
+        contract.create_reveal_series("SEASON_1".to_string(), placeholder, Base64VecU8(root), 3, U128(1_000));
+
+        for token_id in token_ids {
+            testing_env!(context
+                .predecessor_account_id(accounts(2))
+                .storage_usage(env::storage_usage())
+                .attached_deposit(ATTACHED_VALUE)
+                .block_timestamp(100)
+                .build());
+            assert_eq!(contract.nft_mint_unrevealed("SEASON_1".to_string(), accounts(2)), token_id);
+        }
+
+        let entries = token_ids
+            .iter()
+            .zip(finals)
+            .zip(proofs)
+            .map(|((token_id, metadata), proof)| RevealEntry { token_id: token_id.to_string(), metadata, proof })
+            .collect();
+        testing_env!(context
+            .predecessor_account_id(accounts(1))
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(500)
+            .build());
+        (contract, context, entries)
+    }
+
+    #[test]
+    fn test_reveal_in_pages() {
+        let (mut contract, _, mut entries) = mint_unrevealed_series();
+        assert_eq!(contract.nft_token("SEASON_1-0".to_string()).unwrap().metadata.title, "Coming soon");
+        assert_eq!(contract.nft_token_history("SEASON_1-0".to_string(), None, None)[0].price, Some(U128(1_000)));
+
+        let last_page = entries.split_off(2);
+        contract.reveal("SEASON_1".to_string(), entries);
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"token_metadata_update","data":[{"token_ids":["SEASON_1-0","SEASON_1-1"],"memo":"reveal:SEASON_1"}]}"#
+        );
+
+        let token = contract.nft_token("SEASON_1-1".to_string()).unwrap();
+        assert_eq!(token.metadata.title, "Final art #2");
+        // 발행 일자는 민팅 시각이 유지됨
+        assert_eq!(token.metadata.issued_at, U64(100));
+        assert_eq!(contract.nft_token("SEASON_1-2".to_string()).unwrap().metadata.title, "Coming soon");
+
+        contract.reveal("SEASON_1".to_string(), last_page);
+        assert_eq!(contract.nft_token("SEASON_1-2".to_string()).unwrap().metadata.title, "Final art #3");
+        let series = contract.get_reveal_series("SEASON_1".to_string()).unwrap();
+        assert_eq!((series.minted, series.revealed), (3, 3));
+    }
+
+    #[test]
+    #[should_panic(expected = "Metadata of token SEASON_1-0 does not match the committed metadata")]
+    fn test_reveal_with_altered_metadata() {
+        let (mut contract, _, mut entries) = mint_unrevealed_series();
+
+        entries[0].metadata.title = "Something else".to_string();
+        contract.reveal("SEASON_1".to_string(), entries);
+    }
+
+    #[test]
+    #[should_panic(expected = "Metadata of token SEASON_1-1 does not match the committed metadata")]
+    fn test_reveal_with_swapped_metadata() {
+        let (mut contract, _, mut entries) = mint_unrevealed_series();
+
+        let first = entries[0].metadata.clone();
+        entries[0].metadata = entries[1].metadata.clone();
+        entries[1].metadata = first;
+        entries.swap(0, 1);
+        contract.reveal("SEASON_1".to_string(), entries);
+    }
+
+    #[test]
+    #[should_panic(expected = "Token SEASON_1-0 is not an unrevealed token of series SEASON_1")]
+    fn test_reveal_twice() {
+        let (mut contract, _, mut entries) = mint_unrevealed_series();
+
+        let again = RevealEntry {
+            token_id: entries[0].token_id.clone(),
+            metadata: entries[0].metadata.clone(),
+            proof: entries[0].proof.clone(),
+        };
+        entries.truncate(1);
+        contract.reveal("SEASON_1".to_string(), entries);
+        contract.reveal("SEASON_1".to_string(), vec![again]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Reveal series SEASON_1 is sold out")]
+    fn test_nft_mint_unrevealed_beyond_size() {
+        let (mut contract, mut context, _) = mint_unrevealed_series();
+
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_mint_unrevealed("SEASON_1".to_string(), accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "to cover the price and storage")]
+    fn test_nft_mint_unrevealed_without_price() {
+        let (mut contract, mut context, _) = mint_unrevealed_series();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.create_reveal_series(
+            "SEASON_2".to_string(),
+            valid_token_metadata(),
+            Base64VecU8(vec![0; 32]),
+            1,
+            U128(ATTACHED_VALUE),
+        );
+        testing_env!(context.predecessor_account_id(accounts(2)).attached_deposit(ATTACHED_VALUE).build());
+        contract.nft_mint_unrevealed("SEASON_2".to_string(), accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token IDs starting with SEASON_1- are reserved for reveal series SEASON_1")]
+    fn test_nft_mint_with_reveal_series_token_id() {
+        let (mut contract, _, _) = mint_unrevealed_series();
+
+        // 시리즈 밖에서 시리즈 토큰 ID 를 민팅할 수 없음
+        contract.nft_mint("SEASON_1-3".to_string(), valid_token_metadata(), accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token IDs starting with SEASON_1- are reserved for reveal series SEASON_1")]
+    fn test_create_crafting_recipe_with_reveal_series_id() {
+        let (mut contract, _, _) = mint_unrevealed_series();
+
+        contract.create_crafting_recipe(
+            "SEASON_1".to_string(),
+            CraftingRecipe {
+                inputs: vec![CraftingInput { streamer_name: None, tier: None, count: 1 }],
+                output: valid_token_metadata(),
+                output_tier: None,
+            },
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Token IDs of SEASON_2-BONUS would collide with reveal series SEASON_2")]
+    fn test_create_reveal_series_colliding_with_recipe() {
+        let (mut contract, mut context, _) = mint_unrevealed_series();
+        contract.create_crafting_recipe(
+            "SEASON_2-BONUS".to_string(),
+            CraftingRecipe {
+                inputs: vec![CraftingInput { streamer_name: None, tier: None, count: 1 }],
+                output: valid_token_metadata(),
+                output_tier: None,
+            },
+        );
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.create_reveal_series(
+            "SEASON_2".to_string(),
+            valid_token_metadata(),
+            Base64VecU8(vec![0; 32]),
+            1,
+            U128(1_000),
+        );
+    }
+
+    /// TEST for levels.rs
+    fn level_spec(min_engagement: u64, media: &str, traits: &[(&str, &str)]) -> LevelSpec {
+        LevelSpec {
+            min_engagement: U64(min_engagement),
+            media: media.to_string(),
+            media_hash: None,
+            traits: traits
+                .iter()
+                .map(|(trait_type, value)| LevelTrait { trait_type: trait_type.to_string(), value: value.to_string() })
+                .collect(),
+        }
+    }
+
+    // DROP_1 에 레벨 표를 설정하고 accounts(3) 을 오라클로 지정
+    fn contract_with_level_table(levels: Vec<LevelSpec>) -> (Contract, VMContextBuilder) {
+        let (mut contract, mut context) = mint_in_locked_drop();
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_level_oracle(Some(accounts(3)));
+        testing_env!(context.attached_deposit(ATTACHED_VALUE).build());
+        contract.set_level_table("DROP_1".to_string(), levels);
+
+        testing_env!(context
+            .predecessor_account_id(accounts(3))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .block_timestamp(2_000)
+            .build());
+        (contract, context)
+    }
+
+    #[test]
+    fn test_report_engagement_levels_up() {
+        let (mut contract, _) = contract_with_level_table(vec![
+            level_spec(10, "ipfs://level-1", &[("badge", "bronze")]),
+            level_spec(50, "ipfs://level-2", &[("badge", "gold"), ("aura", "on")]),
+        ]);
+
+        assert_eq!(contract.report_engagement("TOKEN_ID_1".to_string(), U64(5)), 0);
+        assert!(contract.nft_level_history("TOKEN_ID_1".to_string(), None, None).is_empty());
+
+        // 두 레벨을 한 번에 넘으면 속성이 모두 적용됨
+        assert_eq!(contract.report_engagement("TOKEN_ID_1".to_string(), U64(60)), 2);
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"token_metadata_update","data":[{"token_ids":["TOKEN_ID_1"],"memo":"level:2"}]}"#
+        );
+
+        let token = contract.nft_token("TOKEN_ID_1".to_string()).unwrap();
+        assert_eq!(token.metadata.media, "ipfs://level-2");
+        assert_eq!(token.metadata.media_hash, None);
+        let level = token.level.unwrap();
+        assert_eq!((level.level, level.engagement), (2, U64(60)));
+        assert_eq!(
+            level.traits,
+            vec![
+                LevelTrait { trait_type: "badge".to_string(), value: "gold".to_string() },
+                LevelTrait { trait_type: "aura".to_string(), value: "on".to_string() },
+            ]
+        );
+
+        let history = contract.nft_level_history("TOKEN_ID_1".to_string(), None, None);
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].level, 0);
+        assert_eq!(history[0].media, valid_token_metadata().media);
+        assert!(history[0].traits.is_empty());
+        assert_eq!(history[0].replaced_at, U64(2_000));
+    }
+
+    #[test]
+    #[should_panic(expected = "Only the level oracle can report engagement")]
+    fn test_report_engagement_by_non_oracle() {
+        let (mut contract, mut context) = contract_with_level_table(vec![level_spec(10, "ipfs://level-1", &[])]);
+
+        testing_env!(context.predecessor_account_id(accounts(1)).build());
+        contract.report_engagement("TOKEN_ID_1".to_string(), U64(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "Engagement of token TOKEN_ID_1 cannot decrease")]
+    fn test_report_decreasing_engagement() {
+        let (mut contract, _) = contract_with_level_table(vec![level_spec(10, "ipfs://level-1", &[])]);
+
+        contract.report_engagement("TOKEN_ID_1".to_string(), U64(20));
+        contract.report_engagement("TOKEN_ID_1".to_string(), U64(15));
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.media must start with one of")]
+    fn test_level_up_with_invalid_media() {
+        let (mut contract, _) = contract_with_level_table(vec![level_spec(10, "ftp://level-1", &[])]);
+
+        contract.report_engagement("TOKEN_ID_1".to_string(), U64(10));
+    }
+
+    #[test]
+    #[should_panic(expected = "TokenMetadata.media must start with one of")]
+    fn test_set_level_table_with_disallowed_media_scheme() {
+        contract_with_level_table(vec![
+            level_spec(10, "ipfs://level-1", &[]),
+            level_spec(50, "javascript:alert(1)", &[]),
+        ]);
+    }
+
+    /// TEST for crafting.rs
+    // accounts(2) 가 TOKEN_ID_1..3 을 가진 상태에서 같은 스트리머의 토큰 3개로 만드는 레시피 등록
+    fn contract_with_recipe(inputs: Vec<CraftingInput>) -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "TOKEN_ID_2", "TOKEN_ID_3"]);
+
+        let mut output = valid_token_metadata();
+        output.title = "Rare clip".to_string();
+        testing_env!(context.attached_deposit(ATTACHED_VALUE).build());
+        contract.create_crafting_recipe(
+            "RARE_CLIP".to_string(),
+            CraftingRecipe { inputs, output, output_tier: Some("rare".to_string()) },
+        );
+
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .build());
+        (contract, context)
+    }
+
+    fn streamer_input(count: u32) -> CraftingInput {
+        CraftingInput { streamer_name: Some("이름".to_string()), tier: None, count }
+    }
+
+    fn all_inputs() -> Vec<TokenId> {
+        vec!["TOKEN_ID_1".to_string(), "TOKEN_ID_2".to_string(), "TOKEN_ID_3".to_string()]
+    }
+
+    #[test]
+    fn test_nft_craft() {
+        let (mut contract, _) = contract_with_recipe(vec![streamer_input(3)]);
+
+        let token_id = contract.nft_craft(all_inputs(), "RARE_CLIP".to_string());
+        assert_eq!(token_id, "RARE_CLIP-3");
+        // 소각된 입력 토큰은 NEP-171 nft_burn 이벤트로도 알림
+        assert!(near_sdk::test_utils::get_logs().contains(
+            &r#"EVENT_JSON:{"standard":"nep171","version":"1.1.0","event":"nft_burn","data":[{"owner_id":"charlie","token_ids":["TOKEN_ID_1","TOKEN_ID_2","TOKEN_ID_3"]}]}"#.to_string()
+        ));
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"nft_craft","data":[{"owner_id":"charlie","recipe_id":"RARE_CLIP","burned_token_ids":["TOKEN_ID_1","TOKEN_ID_2","TOKEN_ID_3"],"token_id":"RARE_CLIP-3"}]}"#
+        );
+
+        for input in all_inputs() {
+            assert!(contract.nft_token(input).is_none());
+        }
+        let token = contract.nft_token(token_id).unwrap();
+        assert_eq!(token.owner_id, accounts(2));
+        assert_eq!(token.metadata.title, "Rare clip");
+        assert_eq!(token.tier, Some("rare".to_string()));
+        assert_eq!(contract.nft_supply_for_owner(accounts(2)), U128(1));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_3 is not owned by charlie")]
+    fn test_nft_craft_with_foreign_token() {
+        let (mut contract, mut context) = contract_with_recipe(vec![streamer_input(3)]);
+
+        contract.nft_transfer(accounts(3), "TOKEN_ID_3".to_string(), None, None);
+        testing_env!(context.storage_usage(env::storage_usage()).build());
+        contract.nft_craft(all_inputs(), "RARE_CLIP".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_3 does not match any remaining input of recipe RARE_CLIP")]
+    fn test_nft_craft_with_unmatched_tier() {
+        let (mut contract, _) = contract_with_recipe(vec![
+            streamer_input(2),
+            CraftingInput { streamer_name: None, tier: Some("rare".to_string()), count: 1 },
+        ]);
+
+        contract.nft_craft(all_inputs(), "RARE_CLIP".to_string());
+    }
+
+    #[test]
+    fn test_nft_craft_assigns_inputs_regardless_of_order() {
+        // 일반 조건이 앞에 있어도 등급이 있는 토큰을 등급 조건에 배정함
+        let (mut contract, _) = contract_with_recipe(vec![
+            CraftingInput { streamer_name: None, tier: None, count: 2 },
+            CraftingInput { streamer_name: None, tier: Some("rare".to_string()), count: 1 },
+        ]);
+        contract.token_tiers.insert(&"TOKEN_ID_1".to_string(), &"rare".to_string());
+
+        let token_id = contract.nft_craft(all_inputs(), "RARE_CLIP".to_string());
+        assert_eq!(contract.nft_token(token_id).unwrap().owner_id, accounts(2));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token TOKEN_ID_1 is listed more than once")]
+    fn test_nft_craft_with_duplicate_input() {
+        let (mut contract, _) = contract_with_recipe(vec![streamer_input(3)]);
+
+        let inputs = vec!["TOKEN_ID_1".to_string(), "TOKEN_ID_2".to_string(), "TOKEN_ID_1".to_string()];
+        contract.nft_craft(inputs, "RARE_CLIP".to_string());
+    }
+
+    /// TEST for badges.rs
+    fn contract_with_badges() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        let badge = |threshold: u32| {
+            let mut metadata = valid_token_metadata();
+            metadata.title = format!("{} clips collected", threshold);
+            AchievementBadge { threshold, metadata }
+        };
+        testing_env!(context.attached_deposit(ATTACHED_VALUE).build());
+        contract.set_achievement_badges(vec![badge(2), badge(3)]);
+        (contract, context)
+    }
+
+    #[test]
+    fn test_badges_awarded_on_mint() {
+        let (mut contract, mut context) = contract_with_badges();
+
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"badge_award","data":[{"owner_id":"charlie","token_id":"badge-2","streamer_name":"이름","threshold":2}]}"#
+        );
+        let badge = contract.nft_token("badge-2".to_string()).unwrap();
+        assert_eq!(badge.owner_id, accounts(2));
+        assert_eq!(badge.metadata.title, "2 clips collected");
+        assert!(contract.is_soulbound("badge-2".to_string()));
+
+        // 배지는 보유 수에 포함되지 않음
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_3"]);
+        assert_eq!(
+            contract.get_awarded_badge(accounts(2), "이름".to_string(), 3),
+            Some("badge-4".to_string())
+        );
+        assert_eq!(contract.nft_supply_for_owner(accounts(2)), U128(5));
+        assert_eq!(contract.held_per_streamer.get(&(accounts(2), "이름".to_string())), Some(3));
+    }
+
+    #[test]
+    fn test_badges_do_not_count_toward_max_supply() {
+        let (mut contract, mut context) = contract_with_badges();
+        testing_env!(context.attached_deposit(1).build());
+        contract.set_mint_limits(MintLimits { max_supply: Some(U64(3)), max_held_per_streamer: None });
+
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+        assert!(contract.is_soulbound("badge-2".to_string()));
+        assert_eq!(contract.get_remaining_supply(), Some(U64(1)));
+
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_3"]);
+        assert_eq!(contract.get_remaining_supply(), Some(U64(0)));
+        assert_eq!(contract.nft_total_supply(), U128(5));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token IDs starting with badge- are reserved for achievement badges")]
+    fn test_nft_mint_with_badge_token_id() {
+        let (mut contract, mut context) = contract_with_badges();
+
+        // 다음 배지 ID 를 미리 민팅해서 배지 발급을 막을 수 없음
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "badge-2"]);
+    }
+
+    #[test]
+    fn test_badges_awarded_once_on_transfer() {
+        let (mut contract, mut context) = contract_with_badges();
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1"]);
+        mint_tokens(&mut contract, &mut context, accounts(3), &["TOKEN_ID_2"]);
+        let available = |contract: &Contract| contract.storage_balance_of(accounts(3)).unwrap().available.0;
+
+        testing_env!(context
+            .predecessor_account_id(accounts(3))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .build());
+        let before = available(&contract);
+        contract.nft_transfer(accounts(2), "TOKEN_ID_2".to_string(), None, None);
+        let badge_transfer_cost = before - available(&contract);
+        assert_eq!(
+            contract.get_awarded_badge(accounts(2), "이름".to_string(), 2),
+            Some("badge-2".to_string())
+        );
+
+        // 기준 아래로 내려갔다가 다시 넘어도 배지는 한 번만 발급됨
+        testing_env!(context.predecessor_account_id(accounts(2)).storage_usage(env::storage_usage()).build());
+        contract.nft_transfer(accounts(3), "TOKEN_ID_2".to_string(), None, None);
+        testing_env!(context.predecessor_account_id(accounts(3)).storage_usage(env::storage_usage()).build());
+        let before = available(&contract);
+        contract.nft_transfer(accounts(2), "TOKEN_ID_2".to_string(), None, None);
+        assert_eq!(contract.nft_supply_for_owner(accounts(2)), U128(3));
+        assert!(contract.nft_token("badge-3".to_string()).is_none());
+
+        // 배지를 발급한 전송은 배지 민팅 스토리지까지 발신자의 스토리지 잔액에서 차감됨
+        assert!(badge_transfer_cost > before - available(&contract));
+    }
+
+    #[test]
+    #[should_panic(expected = "Token badge-2 is a soulbound badge and cannot be transferred")]
+    fn test_badge_transfer() {
+        let (mut contract, mut context) = contract_with_badges();
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .build());
+        contract.nft_transfer(accounts(3), "badge-2".to_string(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Token badge-2 is a soulbound badge and cannot be transferred")]
+    fn test_badge_transfer_offer() {
+        let (mut contract, mut context) = contract_with_badges();
+        mint_tokens(&mut contract, &mut context, accounts(2), &["TOKEN_ID_1", "TOKEN_ID_2"]);
+
+        // 전송 제안으로도 배지를 넘길 수 없음
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_offer_transfer("badge-2".to_string(), accounts(3), U64(1_000));
+    }
+
+    /// TEST for tickets.rs
+    // accounts(2) 에게 1000~2000 사이에 유효한 입장권을 민팅하고 accounts(4) 를 스캐너로 등록
+    fn contract_with_ticket() -> (Contract, VMContextBuilder) {
+        let mut context = get_context(accounts(1));
+        testing_env!(context.build());
+        let mut contract = Contract::new_default_meta(accounts(1));
+
+        testing_env!(context.attached_deposit(1).build());
+        contract.add_venue_scanner(accounts(4));
+
+        testing_env!(context
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_mint_ticket(
+            "TICKET_1".to_string(),
+            valid_token_metadata(),
+            accounts(2),
+            Ticket {
+                event_id: "FANMEETING_2026".to_string(),
+                section: "A".to_string(),
+                seat: Some("12".to_string()),
+                starts_at: U64(1_000),
+                expires_at: U64(2_000),
+                redeemed: false,
+            },
+        );
+        (contract, context)
+    }
+
+    fn check_in_at(contract: &mut Contract, context: &mut VMContextBuilder, timestamp: u64) {
+        testing_env!(context
+            .predecessor_account_id(accounts(4))
+            .attached_deposit(0)
+            .block_timestamp(timestamp)
+            .build());
+        contract.check_in("TICKET_1".to_string());
+    }
+
+    #[test]
+    fn test_check_in() {
+        let (mut contract, mut context) = contract_with_ticket();
+
+        check_in_at(&mut contract, &mut context, 1_500);
+        assert_eq!(
+            near_sdk::test_utils::get_logs().last().unwrap(),
+            r#"EVENT_JSON:{"standard":"mint_culture","version":"1.0.0","event":"ticket_check_in","data":[{"token_id":"TICKET_1","owner_id":"charlie","event_id":"FANMEETING_2026","scanner_id":"eugene"}]}"#
+        );
+        let ticket = contract.nft_token("TICKET_1".to_string()).unwrap().ticket.unwrap();
+        assert!(ticket.redeemed);
+        assert_eq!(ticket.seat, Some("12".to_string()));
+    }
+
+    #[test]
+    #[should_panic(expected = "Ticket TICKET_1 has already been checked in")]
+    fn test_check_in_twice() {
+        let (mut contract, mut context) = contract_with_ticket();
+
+        check_in_at(&mut contract, &mut context, 1_500);
+        check_in_at(&mut contract, &mut context, 1_600);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ticket TICKET_1 is not valid at this time")]
+    fn test_check_in_after_expiry() {
+        let (mut contract, mut context) = contract_with_ticket();
+
+        check_in_at(&mut contract, &mut context, 2_000);
+    }
+
+    #[test]
+    #[should_panic(expected = "Only a venue scanner can check in tickets")]
+    fn test_check_in_by_holder() {
+        let (mut contract, mut context) = contract_with_ticket();
+
+        testing_env!(context.predecessor_account_id(accounts(2)).block_timestamp(1_500).build());
+        contract.check_in("TICKET_1".to_string());
+    }
+
+    #[test]
+    #[should_panic(expected = "Ticket TICKET_1 has been checked in and cannot be transferred")]
+    fn test_transfer_checked_in_ticket() {
+        let (mut contract, mut context) = contract_with_ticket();
+        deposit_storage(&mut contract, &mut context, accounts(2));
+        deposit_storage(&mut contract, &mut context, accounts(3));
+
+        // 입장 처리 전에는 전송할 수 있음
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .build());
+        contract.nft_transfer(accounts(3), "TICKET_1".to_string(), None, None);
+
+        check_in_at(&mut contract, &mut context, 1_500);
+        testing_env!(context
+            .predecessor_account_id(accounts(3))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(1)
+            .build());
+        contract.nft_transfer(accounts(2), "TICKET_1".to_string(), None, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Ticket TICKET_1 has been checked in and cannot be transferred")]
+    fn test_transfer_offer_for_checked_in_ticket() {
+        let (mut contract, mut context) = contract_with_ticket();
+
+        // 입장 처리된 티켓은 전송 제안으로도 넘길 수 없음
+        check_in_at(&mut contract, &mut context, 1_500);
+        testing_env!(context
+            .predecessor_account_id(accounts(2))
+            .storage_usage(env::storage_usage())
+            .attached_deposit(ATTACHED_VALUE)
+            .build());
+        contract.nft_offer_transfer("TICKET_1".to_string(), accounts(3), U64(1_800));
+    }
+
 }
\ No newline at end of file
@@ -0,0 +1,125 @@
+use crate::*;
+use crate::internal::assert_one_yocto;
+
+// 민팅 및 보유 수량 제한. None 이면 제한 없음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug, Default)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintLimits {
+    // 컨트랙트에서 민팅할 수 있는 전체 토큰 수. 소각된 토큰도 포함해서 셈
+    pub max_supply: Option<U64>,
+    // 한 계정이 같은 스트리머의 토큰을 보유할 수 있는 최대 개수
+    pub max_held_per_streamer: Option<u32>,
+}
+
+#[near_bindgen]
+impl Contract {
+    pub fn get_mint_limits(&self) -> MintLimits {
+        self.mint_limits.clone()
+    }
+
+    // 민팅 및 보유 수량 제한 변경 (소유자만 가능). 이미 보유한 토큰에는 영향 없음
+    #[payable]
+    pub fn set_mint_limits(&mut self, limits: MintLimits) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.mint_limits = limits;
+    }
+
+    // 더 민팅할 수 있는 토큰 수
+    pub fn get_remaining_supply(&self) -> Option<U64> {
+        self.mint_limits
+            .max_supply
+            .map(|max_supply| U64(max_supply.0.saturating_sub(self.minted_supply)))
+    }
+
+    // 계정이 드롭에서 더 받을 수 있는 토큰 수
+    pub fn get_remaining_drop_mints(&self, drop_id: DropId, account_id: AccountId) -> Option<u32> {
+        let config = self.drops.get(&drop_id).expect("Drop does not exist");
+        config.max_mints_per_account.map(|max_mints| {
+            max_mints.saturating_sub(self.internal_drop_mints(&drop_id, &account_id))
+        })
+    }
+
+    // 계정이 스트리머의 토큰을 더 보유할 수 있는 개수
+    pub fn get_remaining_streamer_allowance(&self, account_id: AccountId, streamer_name: String) -> Option<u32> {
+        self.mint_limits.max_held_per_streamer.map(|max_held| {
+            max_held.saturating_sub(self.internal_held_per_streamer(&account_id, &streamer_name))
+        })
+    }
+}
+
+impl Contract {
+    fn internal_drop_mints(&self, drop_id: &DropId, account_id: &AccountId) -> u32 {
+        self.drop_mints_per_account
+            .get(&(drop_id.clone(), account_id.clone()))
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn internal_held_per_streamer(&self, account_id: &AccountId, streamer_name: &str) -> u32 {
+        self.held_per_streamer
+            .get(&(account_id.clone(), streamer_name.to_string()))
+            .unwrap_or(0)
+    }
+
+    pub(crate) fn assert_within_max_supply(&self) {
+        if let Some(max_supply) = self.mint_limits.max_supply {
+            assert!(
+                self.minted_supply < max_supply.0,
+                "The maximum supply of {} tokens has been minted",
+                max_supply.0
+            );
+        }
+    }
+
+    // 드롭의 계정별 민팅 제한을 확인하고 민팅 수를 기록합니다.
+    pub(crate) fn internal_count_drop_mint(&mut self, drop_id: &DropId, account_id: &AccountId) {
+        let config = self.drops.get(drop_id).expect("Drop does not exist");
+        let minted = self.internal_drop_mints(drop_id, account_id);
+        if let Some(max_mints) = config.max_mints_per_account {
+            assert!(
+                minted < max_mints,
+                "Account {} already minted the maximum of {} tokens in drop {}",
+                account_id,
+                max_mints,
+                drop_id
+            );
+        }
+        self.drop_mints_per_account
+            .insert(&(drop_id.clone(), account_id.clone()), &(minted + 1));
+    }
+
+    // 토큰을 받으면 스트리머별 보유 제한을 넘는지 확인
+    pub(crate) fn assert_within_held_limit(&self, account_id: &AccountId, token_id: &TokenId) {
+        if let Some(max_held) = self.mint_limits.max_held_per_streamer {
+            let streamer_name = self.token_metadata_by_id.get(token_id).expect("No token").streamer_name;
+            assert!(
+                self.internal_held_per_streamer(account_id, &streamer_name) < max_held,
+                "Account {} already holds the maximum of {} tokens of {}",
+                account_id,
+                max_held,
+                streamer_name
+            );
+        }
+    }
+
+    // 소유자가 바뀔 때 스트리머별 보유 수를 갱신합니다. 메타데이터가 없는 토큰은 세지 않습니다.
+    pub(crate) fn internal_update_held_per_streamer(&mut self, account_id: &AccountId, token_id: &TokenId, added: bool) {
+        // 업적 배지는 보유 수에 포함하지 않음
+        if self.badge_tokens.contains(token_id) {
+            return;
+        }
+        let streamer_name = match self.token_metadata_by_id.get(token_id) {
+            Some(metadata) => metadata.streamer_name,
+            None => return,
+        };
+        let key = (account_id.clone(), streamer_name);
+        let held = self.held_per_streamer.get(&key).unwrap_or(0);
+        let held = if added { held + 1 } else { held.saturating_sub(1) };
+
+        if held == 0 {
+            self.held_per_streamer.remove(&key);
+        } else {
+            self.held_per_streamer.insert(&key, &held);
+        }
+    }
+}
@@ -1,6 +1,12 @@
 use crate::*;
+use crate::internal::{assert_at_least_one_yocto, refund_deposit};
+use near_sdk::serde::Deserializer;
+use near_sdk::serde_json::Value;
 pub type TokenId = String;
 
+// 컨트랙트 메타데이터가 따를 수 있는 NEP-177 스펙 버전
+pub const SUPPORTED_METADATA_SPECS: [&str; 2] = ["nft-1.0.0", "nft-2.0.0"];
+
 
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
@@ -20,7 +26,7 @@ pub struct NFTContractMetadata {
     pub reference_hash: Option<Base64VecU8>, // Base64-encoded sha256 hash of JSON from reference field. Required if `reference` is included.
 }
 
-#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
 #[serde(crate = "near_sdk::serde")]
 pub struct TokenMetadata {
     // Streamer name
@@ -39,8 +45,29 @@ pub struct TokenMetadata {
     // NFT 설명 -
     pub description: Option<String>,
 
-    // 토큰 발행 일자
-    pub issued_at: String
+    // 토큰 발행 일자 (블록 타임스탬프, 나노초). 민팅 시 컨트랙트가 직접 기록하므로 호출자가 넣은 값은 무시됨
+    // 마이그레이션된 토큰은 발행 시각을 알 수 없으므로 0
+    #[serde(default = "default_issued_at", deserialize_with = "deserialize_issued_at")]
+    pub issued_at: U64,
+
+    // 마이그레이션 이전에 호출자가 문자열로 넣었던 발행 일자
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub legacy_issued_at: Option<String>,
+}
+
+fn default_issued_at() -> U64 {
+    U64(0)
+}
+
+// 이전 클라이언트는 issued_at 에 임의의 문자열(예: "오늘")을 넣어 보냈음.
+// 민팅 시 어차피 무시되는 값이므로 숫자로 읽을 수 없는 값은 0 으로 받음
+fn deserialize_issued_at<'de, D: Deserializer<'de>>(deserializer: D) -> Result<U64, D::Error> {
+    let issued_at = match Value::deserialize(deserializer)? {
+        Value::String(issued_at) => issued_at.parse().unwrap_or(0),
+        Value::Number(issued_at) => issued_at.as_u64().unwrap_or(0),
+        _ => 0,
+    };
+    Ok(U64(issued_at))
 }
 
 #[derive(BorshDeserialize, BorshSerialize)]
@@ -50,9 +77,22 @@ pub struct Token {
     // tokenMetaById 자료형으로 저장됨.
     pub owner_id: AccountId,
     pub approved_account_ids: HashMap<AccountId, u64>,
+    // 만료 시각(블록 타임스탬프, 나노초)이 지정된 승인의 만료 시각
+    pub approval_expires_at: HashMap<AccountId, u64>,
     pub next_approval_id: u64,
 }
 
+impl Token {
+    // 만료되지 않은 승인이 있으면 그 승인 ID를 반환
+    pub(crate) fn active_approval_id(&self, account_id: &AccountId) -> Option<u64> {
+        let approval_id = self.approved_account_ids.get(account_id)?;
+        match self.approval_expires_at.get(account_id) {
+            Some(expires_at) if *expires_at <= env::block_timestamp() => None,
+            _ => Some(*approval_id),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize)]
 #[serde(crate = "near_sdk::serde")]
 pub struct JsonToken {
@@ -64,7 +104,70 @@ pub struct JsonToken {
 
     pub metadata: TokenMetadata,
 
+    // metadata.issued_at 의 밀리초 단위 값
+    pub issued_at_ms: u64,
+
+    // 민팅 순번. 먼저 민팅된 토큰일수록 작음
+    pub mint_seq: u64,
+
     pub approved_account_ids: HashMap<AccountId, u64>,
+
+    // 승인 만료 시각 (나노초). 만료된 승인은 무효이며 prune_expired_approvals 로 정리할 수 있음
+    pub approval_expires_at: HashMap<AccountId, U64>,
+
+    // 토큰이 민팅된 드롭
+    pub drop_id: Option<DropId>,
+
+    // 전송 잠금이 풀리는 시각 (나노초). 잠겨있지 않으면 None
+    pub transfer_locked_until: Option<U64>,
+
+    // 모더레이터에 의해 동결되었는지 여부와 그 사유
+    pub frozen: bool,
+    pub frozen_reason: Option<String>,
+
+    // 참여도에 따라 오른 레벨과 속성. 레벨 표가 있는 드롭의 토큰에만 있음
+    pub level: Option<TokenLevel>,
+
+    // 희귀도 등급. 미스터리 박스나 제작으로 민팅된 토큰에만 있음
+    pub tier: Option<String>,
+
+    // 입장권 토큰이면 입장권 정보
+    pub ticket: Option<Ticket>,
+}
+
+impl NFTContractMetadata {
+    // 변경하려는 컨트랙트 메타데이터가 올바른지 검사
+    pub(crate) fn assert_valid(&self) {
+        assert!(
+            SUPPORTED_METADATA_SPECS.contains(&self.spec.as_str()),
+            "Unsupported metadata spec {}, expected one of {:?}",
+            self.spec,
+            SUPPORTED_METADATA_SPECS,
+        );
+        assert!(!self.name.is_empty(), "Contract name must not be empty");
+        assert!(!self.symbol.is_empty(), "Contract symbol must not be empty");
+
+        // reference가 있으면 reference_hash가 반드시 있어야 함 (sha256, 32바이트)
+        if self.reference.is_some() {
+            let reference_hash = self
+                .reference_hash
+                .as_ref()
+                .expect("reference_hash is required when reference is set");
+            assert_eq!(
+                reference_hash.0.len(),
+                32,
+                "reference_hash must be a base64-encoded sha256 hash (32 bytes)"
+            );
+        }
+    }
+}
+
+// 커서 기반 페이지네이션 결과. next_cursor 가 None 이면 마지막 페이지
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonTokenPage {
+    pub tokens: Vec<JsonToken>,
+    pub next_cursor: Option<TokenId>,
 }
 
 pub trait NonFungibleTokenMetadata {
@@ -77,4 +180,28 @@ impl NonFungibleTokenMetadata for Contract {
         // 메타데이터 쿼리를 위한 함수
         self.metadata.get().unwrap()
     }
-}
\ No newline at end of file
+}
+
+#[near_bindgen]
+impl Contract {
+    // 컨트랙트 메타데이터 변경 (소유자만 가능)
+    #[payable]
+    pub fn set_contract_metadata(&mut self, metadata: NFTContractMetadata, memo: Option<String>) {
+        assert_at_least_one_yocto();
+        self.assert_owner();
+        metadata.assert_valid();
+
+        let initial_storage_usage = env::storage_usage();
+
+        self.metadata.set(&metadata);
+
+        // 메타데이터가 커진 만큼만 보증금에서 차감하고 나머지는 환불
+        let required_storage_in_bytes = env::storage_usage().saturating_sub(initial_storage_usage);
+        refund_deposit(required_storage_in_bytes);
+
+        EventLog::nep171(EventLogVariant::ContractMetadataUpdate(vec![
+            ContractMetadataUpdateLog { memo },
+        ]))
+        .emit();
+    }
+}
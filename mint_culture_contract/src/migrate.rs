@@ -0,0 +1,143 @@
+use crate::*;
+
+// 현재 상태 구조 버전. 이전 상태는 0
+pub const STATE_VERSION: u32 = 1;
+
+
+// issued_at 이 호출자가 넣은 문자열이던 시절의 토큰 메타데이터
+#[derive(BorshDeserialize, BorshSerialize)]
+pub struct OldTokenMetadata {
+    pub streamer_name: String,
+    pub title: String,
+    pub serial_number: String,
+    pub media: String,
+    pub media_hash: Option<Base64VecU8>,
+    pub description: Option<String>,
+    pub issued_at: String,
+}
+
+// 승인 만료 시각이 없던 시절의 토큰
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct OldToken {
+    pub owner_id: AccountId,
+    pub approved_account_ids: HashMap<AccountId, u64>,
+    pub next_approval_id: u64,
+}
+
+// 마이그레이션 이전의 컨트랙트 상태
+#[derive(BorshDeserialize, BorshSerialize)]
+pub(crate) struct OldContract {
+    pub owner_id: AccountId,
+    pub tokens_per_owner: LookupMap<AccountId, UnorderedSet<TokenId>>,
+    pub tokens_by_id: LookupMap<TokenId, OldToken>,
+    pub token_metadata_by_id: UnorderedMap<TokenId, OldTokenMetadata>,
+    pub metadata: LazyOption<NFTContractMetadata>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MigrationStatus {
+    pub state_version: u32,
+    // 아직 새 구조로 옮기지 않은 토큰 수
+    pub remaining_tokens: U64,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 기존 상태를 읽어 새 구조로 옮깁니다. 새로 추가된 필드는 `new` 의 기본값을 사용합니다.
+    // 토큰은 가스 한도를 넘지 않도록 여기서 옮기지 않고 migrate_tokens 로 나눠서 옮깁니다.
+    #[private]
+    #[init(ignore_state)]
+    pub fn migrate() -> Self {
+        let old: OldContract = env::state_read().expect("No contract state to migrate");
+
+        // LookupMap 은 접두사만 가지므로 같은 StorageKey 로 다시 만들면 기존 데이터를 그대로 가리킵니다.
+        let mut this = Self::new(
+            old.owner_id,
+            old.metadata.get().expect("No contract metadata to migrate"),
+        );
+
+        // 기존 토큰은 저장되어 있던 순서대로 민팅 순번을 받으므로 그 다음 순번부터 새로 민팅됩니다.
+        this.next_mint_seq = old.token_metadata_by_id.len();
+        this.minted_supply = old.token_metadata_by_id.len();
+        if !old.token_metadata_by_id.is_empty() {
+            this.state_version = 0;
+            this.legacy_token_metadata = Some(old.token_metadata_by_id);
+        }
+        this
+    }
+
+    // 이전 구조의 토큰을 최대 limit 개 새 구조로 옮기고 남은 토큰 수를 반환합니다 (소유자만 가능).
+    // 남은 토큰이 0이 될 때까지 여러 번 호출해야 하며, 그 전까지 민팅, 전송, 소각은 막힙니다.
+    pub fn migrate_tokens(&mut self, limit: u32) -> U64 {
+        self.assert_owner();
+        let mut legacy = self.legacy_token_metadata.take().expect("There are no tokens to migrate");
+        let mut old_tokens_by_id: LookupMap<TokenId, OldToken> =
+            LookupMap::new(StorageKey::TokensById.try_to_vec().unwrap());
+
+        // 뒤에서부터 옮기면 이전 맵에서 꺼낼 때 다른 항목이 자리를 옮기지 않으므로
+        // 남은 개수가 곧 다음에 옮길 위치(커서)가 됩니다.
+        for _ in 0..limit {
+            let mint_seq = legacy.len();
+            if mint_seq == 0 {
+                break;
+            }
+            let mint_seq = mint_seq - 1;
+            let token_id = legacy.keys_as_vector().get(mint_seq).unwrap();
+            let metadata = legacy.remove(&token_id).unwrap();
+
+            self.token_metadata_by_id.insert(
+                &token_id,
+                &TokenMetadata {
+                    streamer_name: metadata.streamer_name,
+                    title: metadata.title,
+                    serial_number: metadata.serial_number,
+                    media: metadata.media,
+                    media_hash: metadata.media_hash,
+                    description: metadata.description,
+                    issued_at: U64(0),
+                    legacy_issued_at: Some(metadata.issued_at),
+                },
+            );
+
+            // 이전 구조의 토큰을 꺼낸 뒤 새 구조로 다시 저장합니다.
+            let token = old_tokens_by_id.remove(&token_id).expect("No Exist Token");
+            let owner_id = token.owner_id.clone();
+            self.tokens_by_id.insert(
+                &token_id,
+                &Token {
+                    owner_id: token.owner_id,
+                    approved_account_ids: token.approved_account_ids,
+                    approval_expires_at: Default::default(),
+                    next_approval_id: token.next_approval_id,
+                },
+            );
+
+            self.mint_seq_by_id.insert(&token_id, &mint_seq);
+            self.tokens_by_mint_seq.insert(&mint_seq, &token_id);
+            self.internal_add_token_to_owner_by_mint_seq(&owner_id, &token_id);
+            self.internal_update_held_per_streamer(&owner_id, &token_id, true);
+        }
+
+        let remaining = legacy.len();
+        if remaining == 0 {
+            self.state_version = STATE_VERSION;
+        } else {
+            self.legacy_token_metadata = Some(legacy);
+        }
+        U64(remaining)
+    }
+
+    pub fn get_migration_status(&self) -> MigrationStatus {
+        MigrationStatus {
+            state_version: self.state_version,
+            remaining_tokens: U64(self.legacy_token_metadata.as_ref().map_or(0, |legacy| legacy.len())),
+        }
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_migrated(&self) {
+        assert_eq!(self.state_version, STATE_VERSION, "Token migration is in progress");
+    }
+}
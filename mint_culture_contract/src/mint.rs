@@ -1,8 +1,29 @@
 use crate::*;
-use crate::internal::refund_deposit;
+use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, refund_deposit};
 
 #[near_bindgen]
 impl Contract {
+    // 민터 추가 (소유자만 가능)
+    #[payable]
+    pub fn add_minter(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.minters.insert(&account_id);
+    }
+
+    // 민터 제거 (소유자만 가능)
+    #[payable]
+    pub fn remove_minter(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.minters.remove(&account_id);
+    }
+
+    pub fn get_minters(&self) -> Vec<AccountId> {
+        self.minters.to_vec()
+    }
+
+    // 토큰을 직접 민팅합니다 (소유자 또는 민터만 가능)
     #[payable]
     pub fn nft_mint(
         &mut self,
@@ -10,18 +31,116 @@ impl Contract {
         metadata: TokenMetadata,
         receiver_id: AccountId,
     ) {
+        self.assert_minter();
         // 계약에서 사용 중인 초기 저장소를 측정합니다.
         let initial_storage_usage = env::storage_usage();
 
+        self.internal_mint(token_id, metadata, receiver_id, None);
+
+        // 사용된 필수 스토리지 계산 - 초기
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+
+        // 사용자가 너무 많이 첨부한 경우 초과 저장용량을 환불합니다.
+        refund_deposit(required_storage_in_bytes);
+
+    }
+
+    // 드롭에 속한 토큰을 민팅합니다 (소유자 또는 민터만 가능). 드롭의 전송 잠금 등 설정이 토큰에 적용됩니다.
+    #[payable]
+    pub fn nft_mint_in_drop(
+        &mut self,
+        token_id: TokenId,
+        metadata: TokenMetadata,
+        receiver_id: AccountId,
+        drop_id: DropId,
+    ) {
+        self.assert_minter();
+        let initial_storage_usage = env::storage_usage();
+
+        self.internal_mint(token_id, metadata, receiver_id, Some(drop_id));
+
+        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+        refund_deposit(required_storage_in_bytes);
+    }
+
+    // 토큰을 소각합니다 (토큰 소유자만 가능). 소유 이력은 소각 후에도 남습니다.
+    #[payable]
+    pub fn nft_burn(&mut self, token_id: TokenId, memo: Option<String>) {
+        assert_at_least_one_yocto();
+        let owner_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
+
+        self.internal_burn(&owner_id, &token_id);
+
+        // 늘어난 스토리지만 첨부한 보증금에서 받음. 토큰 스토리지는 민팅한 쪽이 냈으므로
+        // 해제된 만큼은 소각하는 계정에 돌려주지 않고 컨트랙트에 남김
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+
+        EventLog::nep171(EventLogVariant::NftBurn(vec![NftBurnLog {
+            owner_id,
+            token_ids: vec![token_id],
+            authorized_id: None,
+            memo,
+        }]))
+        .emit();
+    }
+}
+
+impl Contract {
+    // 컨트랙트 소유자 또는 민터만 호출할 수 있는 함수에서 사용
+    pub(crate) fn assert_minter(&self) {
+        let predecessor_account_id = env::predecessor_account_id();
+        assert!(
+            predecessor_account_id == self.owner_id || self.minters.contains(&predecessor_account_id),
+            "Only the owner or a minter can call this method"
+        );
+    }
+
+    // 토큰을 민팅합니다. 스토리지 비용 정산은 호출하는 쪽에서 해야 합니다.
+    pub(crate) fn internal_mint(
+        &mut self,
+        token_id: TokenId,
+        metadata: TokenMetadata,
+        receiver_id: AccountId,
+        drop_id: Option<DropId>,
+    ) {
+        self.internal_mint_with_price(token_id, metadata, receiver_id, drop_id, None);
+    }
+
+    // 판매 가격을 받고 토큰을 민팅합니다. 가격은 민팅 이력에 기록됩니다.
+    pub(crate) fn internal_mint_with_price(
+        &mut self,
+        token_id: TokenId,
+        metadata: TokenMetadata,
+        receiver_id: AccountId,
+        drop_id: Option<DropId>,
+        price: Option<U128>,
+    ) {
+        self.assert_migrated();
+        // 메타데이터가 올바른지 먼저 검사합니다.
+        metadata.assert_valid(&self.metadata_limits);
+
+        // 차단된 계정은 민팅하거나 토큰을 받을 수 없습니다.
+        self.assert_not_blocked(&env::predecessor_account_id());
+        self.assert_not_blocked(&receiver_id);
+
+        // 발행 일자는 호출자를 믿지 않고 블록 타임스탬프로 기록합니다.
+        let mut metadata = metadata;
+        metadata.issued_at = U64(env::block_timestamp());
+        metadata.legacy_issued_at = None;
+
         // 소유자 ID를 포함하는 토큰 구조체 지정
         let token = Token {
             owner_id: receiver_id,
             approved_account_ids: Default::default(),
+            approval_expires_at: Default::default(),
             next_approval_id: 0,
         };
 
         // 토큰 ID와 토큰 구조체를 삽입하고 토큰이 존재하지 않는지 확인합니다.
         let token_id = &token_id[..];
+        self.assert_not_badge_token_id(token_id);
+        self.assert_not_reveal_token_id(token_id);
 
         assert!(
             self.tokens_by_id.insert(&token_id.to_string(), &token).is_none(),
@@ -31,16 +150,67 @@ impl Contract {
         // 토큰 ID 및 메타데이터 삽입
         self.token_metadata_by_id.insert(&token_id.to_string(), &metadata);
 
+        // 전체 발행량과 받는 사람의 스트리머별 보유 제한을 확인합니다.
+        // 업적 배지는 다른 민팅이나 전송 도중 자동으로 발급되므로 제한을 확인하지 않습니다.
+        if !self.badge_tokens.contains(&token_id.to_string()) {
+            self.assert_within_max_supply();
+            self.assert_within_held_limit(&token.owner_id, &token_id.to_string());
+            self.minted_supply += 1;
+        }
+
+        // 민팅 순번을 부여하고 민팅 시각 인덱스에 기록합니다.
+        let mint_seq = self.internal_assign_mint_seq(&token_id.to_string());
+        let issued_at = metadata.issued_at.0;
+        if !self.first_mint_seq_by_timestamp.contains_key(&issued_at) {
+            self.first_mint_seq_by_timestamp.insert(&issued_at, &mint_seq);
+        }
+
+        // 드롭에 속한 토큰이면 드롭을 기록합니다.
+        if let Some(drop_id) = drop_id {
+            assert!(self.drops.get(&drop_id).is_some(), "Drop {} does not exist", drop_id);
+            self.internal_count_drop_mint(&drop_id, &token.owner_id);
+            self.drop_by_token.insert(&token_id.to_string(), &drop_id);
+        }
+
         // 소유자에게 토큰을 추가하기 위한 내부 메서드를 호출합니다.
         self.internal_add_token_to_owner(&token.owner_id,&token_id.to_string());
 
-        // 사용된 필수 스토리지 계산 - 초기
-        let required_storage_in_bytes = env::storage_usage() - initial_storage_usage;
+        self.internal_record_provenance(&token_id.to_string(), &token.owner_id, ProvenanceKind::Mint, price);
 
-        // 사용자가 너무 많이 첨부한 경우 초과 저장용량을 환불합니다.
+        EventLog::nep171(EventLogVariant::NftMint(vec![NftMintLog {
+            owner_id: token.owner_id.clone(),
+            token_ids: vec![token_id.to_string()],
+            memo: None,
+        }]))
+        .emit();
 
-        // 구현 해야함 - internal.rs를 만들어서 구현할 것
-        refund_deposit(required_storage_in_bytes);
+        self.internal_award_badges(&token.owner_id, &token_id.to_string());
+    }
+
+    // 토큰과 모든 인덱스에서 토큰을 제거합니다. 스토리지 비용 정산은 호출하는 쪽에서 해야 합니다.
+    pub(crate) fn internal_burn(&mut self, owner_id: &AccountId, token_id: &TokenId) -> Token {
+        self.assert_migrated();
+        let token = self.tokens_by_id.get(token_id).expect("No token");
+        assert_eq!(&token.owner_id, owner_id, "Only the token owner can burn the token");
+        self.assert_no_transfer_offer(token_id);
+        self.assert_not_frozen(token_id);
+        self.assert_not_blocked(owner_id);
+
+        self.internal_remove_token_from_owner(owner_id, token_id);
+        self.tokens_by_id.remove(token_id);
+        self.token_metadata_by_id.remove(token_id);
+        self.drop_by_token.remove(token_id);
+        self.unrevealed_series_by_token.remove(token_id);
+        self.token_levels.remove(token_id);
+        self.token_tiers.remove(token_id);
+        self.badge_tokens.remove(token_id);
+        self.tickets.remove(token_id);
+        // 페이지 cursor 로 쓰일 수 있도록 mint_seq_by_id 는 남겨둡니다.
+        if let Some(mint_seq) = self.mint_seq_by_id.get(token_id) {
+            self.tokens_by_mint_seq.remove(&mint_seq);
+        }
 
+        self.internal_record_provenance(token_id, owner_id, ProvenanceKind::Burn, None);
+        token
     }
-}
\ No newline at end of file
+}
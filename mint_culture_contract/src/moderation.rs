@@ -0,0 +1,179 @@
+use crate::*;
+use crate::internal::assert_one_yocto;
+
+// 계정을 차단한 사유
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum BlockReason {
+    LegalRequest,
+    Sanctions,
+    Fraud,
+    Other,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct BlockedAccount {
+    pub reason: BlockReason,
+    pub memo: Option<String>,
+    pub blocked_by: AccountId,
+    pub blocked_at: U64,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct JsonBlockedAccount {
+    pub account_id: AccountId,
+    #[serde(flatten)]
+    pub blocked: BlockedAccount,
+}
+
+// 분쟁 중이거나 도난 신고된 토큰의 동결 정보
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct FrozenToken {
+    pub reason: String,
+    pub frozen_by: AccountId,
+    pub frozen_at: U64,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 모더레이터 추가 (소유자만 가능)
+    #[payable]
+    pub fn add_moderator(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.moderators.insert(&account_id);
+    }
+
+    // 모더레이터 제거 (소유자만 가능)
+    #[payable]
+    pub fn remove_moderator(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.moderators.remove(&account_id);
+    }
+
+    pub fn get_moderators(&self) -> Vec<AccountId> {
+        self.moderators.to_vec()
+    }
+
+    // 계정 차단. 차단된 계정은 토큰을 받거나 보내거나 승인할 수 없음 (모더레이터만 가능)
+    #[payable]
+    pub fn block_account(&mut self, account_id: AccountId, reason: BlockReason, memo: Option<String>) {
+        assert_one_yocto();
+        self.assert_moderator();
+
+        let blocked = BlockedAccount {
+            reason,
+            memo,
+            blocked_by: env::predecessor_account_id(),
+            blocked_at: U64(env::block_timestamp()),
+        };
+        self.blocked_accounts.insert(&account_id, &blocked);
+
+        EventLog::mint_culture(EventLogVariant::AccountBlock(vec![AccountBlockLog {
+            account_id,
+            reason: Some(blocked.reason),
+            memo: blocked.memo,
+            moderator_id: blocked.blocked_by,
+        }]))
+        .emit();
+    }
+
+    // 계정 차단 해제 (모더레이터만 가능)
+    #[payable]
+    pub fn unblock_account(&mut self, account_id: AccountId, memo: Option<String>) {
+        assert_one_yocto();
+        self.assert_moderator();
+
+        if self.blocked_accounts.remove(&account_id).is_some() {
+            EventLog::mint_culture(EventLogVariant::AccountUnblock(vec![AccountBlockLog {
+                account_id,
+                reason: None,
+                memo,
+                moderator_id: env::predecessor_account_id(),
+            }]))
+            .emit();
+        }
+    }
+
+    // 토큰 동결. 동결된 토큰은 전송, 승인, 전송 제안, 소각할 수 없음 (모더레이터만 가능)
+    #[payable]
+    pub fn freeze_token(&mut self, token_id: TokenId, reason: String) {
+        assert_one_yocto();
+        self.assert_moderator();
+        assert!(self.tokens_by_id.contains_key(&token_id), "No token");
+        assert!(!reason.trim().is_empty(), "A reason is required to freeze a token");
+
+        let frozen = FrozenToken {
+            reason,
+            frozen_by: env::predecessor_account_id(),
+            frozen_at: U64(env::block_timestamp()),
+        };
+        self.frozen_tokens.insert(&token_id, &frozen);
+
+        EventLog::mint_culture(EventLogVariant::TokenFreeze(vec![TokenFreezeLog {
+            token_id,
+            reason: Some(frozen.reason),
+            moderator_id: frozen.frozen_by,
+        }]))
+        .emit();
+    }
+
+    // 토큰 동결 해제 (모더레이터만 가능)
+    #[payable]
+    pub fn unfreeze_token(&mut self, token_id: TokenId) {
+        assert_one_yocto();
+        self.assert_moderator();
+
+        if self.frozen_tokens.remove(&token_id).is_some() {
+            EventLog::mint_culture(EventLogVariant::TokenUnfreeze(vec![TokenFreezeLog {
+                token_id,
+                reason: None,
+                moderator_id: env::predecessor_account_id(),
+            }]))
+            .emit();
+        }
+    }
+
+    pub fn is_account_blocked(&self, account_id: AccountId) -> bool {
+        self.blocked_accounts.get(&account_id).is_some()
+    }
+
+    // 차단된 계정 목록
+    pub fn get_blocked_accounts(&self, from_index: Option<U128>, limit: Option<u64>) -> Vec<JsonBlockedAccount> {
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+
+        self.blocked_accounts.iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .map(|(account_id, blocked)| JsonBlockedAccount { account_id, blocked })
+            .collect()
+    }
+}
+
+impl Contract {
+    // 컨트랙트 소유자 또는 모더레이터만 호출할 수 있는 함수에서 사용
+    pub(crate) fn assert_moderator(&self) {
+        let predecessor_account_id = env::predecessor_account_id();
+        assert!(
+            predecessor_account_id == self.owner_id || self.moderators.contains(&predecessor_account_id),
+            "Only a moderator can call this method"
+        );
+    }
+
+    pub(crate) fn assert_not_frozen(&self, token_id: &TokenId) {
+        if let Some(frozen) = self.frozen_tokens.get(token_id) {
+            panic!("Token {} is frozen: {}", token_id, frozen.reason);
+        }
+    }
+
+    pub(crate) fn assert_not_blocked(&self, account_id: &AccountId) {
+        if let Some(blocked) = self.blocked_accounts.get(account_id) {
+            panic!("Account {} is blocked ({:?})", account_id, blocked.reason);
+        }
+    }
+}
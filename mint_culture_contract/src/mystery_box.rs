@@ -0,0 +1,136 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, charge_price_and_storage};
+
+pub type MysteryBoxId = String;
+
+// 미스터리 박스에서 나올 수 있는 희귀도 등급
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RarityTier {
+    pub name: String,
+    // 다른 등급과의 상대적인 확률. 남은 수량이 없는 등급은 제외하고 계산함
+    pub weight: u32,
+    pub max_supply: u32,
+    #[serde(default)]
+    pub minted: u32,
+    // 이 등급으로 민팅되는 토큰의 메타데이터
+    pub metadata: TokenMetadata,
+}
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MysteryBox {
+    // 박스 하나의 가격 (yoctoNEAR). 컨트랙트 소유자에게 전송됨
+    pub price: U128,
+    pub tiers: Vec<RarityTier>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RarityTierSupply {
+    pub name: String,
+    pub remaining: u32,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 미스터리 박스 생성 (소유자만 가능)
+    #[payable]
+    pub fn create_mystery_box(&mut self, box_id: MysteryBoxId, price: U128, tiers: Vec<RarityTier>) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(!tiers.is_empty(), "A mystery box needs at least one rarity tier");
+        for tier in &tiers {
+            assert!(tier.weight > 0, "Rarity tier {} must have a positive weight", tier.name);
+            assert_eq!(tier.minted, 0, "Rarity tier {} must start with nothing minted", tier.name);
+            tier.metadata.assert_valid(&self.metadata_limits);
+        }
+        // 상자에서 민팅할 토큰 ID 가 공개 시리즈 토큰 ID 와 겹치지 않아야 함
+        self.assert_not_reveal_token_id(&format!("{}-", box_id));
+
+        let mystery_box = MysteryBox { price, tiers };
+        assert!(
+            self.mystery_boxes.insert(&box_id, &mystery_box).is_none(),
+            "Mystery box {} already exists",
+            box_id
+        );
+    }
+
+    pub fn get_mystery_box(&self, box_id: MysteryBoxId) -> Option<MysteryBox> {
+        self.mystery_boxes.get(&box_id)
+    }
+
+    // 등급별 남은 수량
+    pub fn get_mystery_box_supply(&self, box_id: MysteryBoxId) -> Vec<RarityTierSupply> {
+        let mystery_box = self.mystery_boxes.get(&box_id).expect("Mystery box does not exist");
+        mystery_box
+            .tiers
+            .iter()
+            .map(|tier| RarityTierSupply {
+                name: tier.name.clone(),
+                remaining: tier.max_supply - tier.minted,
+            })
+            .collect()
+    }
+
+    // 미스터리 박스를 구매하여 엽니다. 가격과 스토리지 비용을 첨부해야 하며 민팅된 토큰 ID를 반환합니다.
+    // 같은 블록에서는 random_seed 가 같으므로 같은 결과가 나올 수 있습니다.
+    #[payable]
+    pub fn nft_open_mystery_box(&mut self, box_id: MysteryBoxId) -> TokenId {
+        let mut mystery_box = self.mystery_boxes.get(&box_id).expect("Mystery box does not exist");
+        let tier_index = pick_tier(&mystery_box.tiers, &env::random_seed());
+
+        let initial_storage_usage = env::storage_usage();
+        let receiver_id = env::predecessor_account_id();
+        let token_id = format!("{}-{}", box_id, self.next_mint_seq);
+
+        let tier = &mut mystery_box.tiers[tier_index];
+        tier.minted += 1;
+        let tier_name = tier.name.clone();
+        let metadata = tier.metadata.clone();
+        self.mystery_boxes.insert(&box_id, &mystery_box);
+
+        self.internal_mint_with_price(token_id.clone(), metadata, receiver_id.clone(), None, Some(mystery_box.price));
+        self.token_tiers.insert(&token_id, &tier_name);
+        charge_price_and_storage(
+            env::storage_usage().saturating_sub(initial_storage_usage),
+            mystery_box.price.0,
+            self.owner_id.clone(),
+        );
+
+        EventLog::mint_culture(EventLogVariant::MysteryBoxReveal(vec![MysteryBoxRevealLog {
+            box_id,
+            token_id: token_id.clone(),
+            owner_id: receiver_id,
+            tier: tier_name,
+        }]))
+        .emit();
+
+        token_id
+    }
+}
+
+// 남은 수량이 있는 등급 중에서 가중치에 따라 하나를 고름
+fn pick_tier(tiers: &[RarityTier], random_seed: &[u8]) -> usize {
+    let total_weight: u64 = tiers
+        .iter()
+        .filter(|tier| tier.minted < tier.max_supply)
+        .map(|tier| u64::from(tier.weight))
+        .sum();
+    assert!(total_weight > 0, "The mystery box is sold out");
+
+    let mut random_bytes = [0u8; 8];
+    random_bytes.copy_from_slice(&random_seed[..8]);
+    let mut roll = u64::from_le_bytes(random_bytes) % total_weight;
+
+    for (index, tier) in tiers.iter().enumerate() {
+        if tier.minted >= tier.max_supply {
+            continue;
+        }
+        if roll < u64::from(tier.weight) {
+            return index;
+        }
+        roll -= u64::from(tier.weight);
+    }
+    unreachable!()
+}
@@ -1,9 +1,13 @@
 use crate::*;
-use near_sdk::{assert_one_yocto, ext_contract, Gas, log, PromiseResult};
-use crate::internal::refund_approved_account_ids;
+use near_sdk::{ext_contract, Gas, log, PromiseResult};
+use crate::internal::{assert_one_yocto, refund_approved_account_ids};
 
-const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(10_000_000_000_000);
-const GAS_FOR_NFT_ON_TRANSFER: Gas = Gas(25_000_000_000_000);
+// nft_resolve_transfer 에 예약하는 가스. 토큰을 되돌릴 때 소유자 인덱스 갱신과 승인 환불까지 감당해야 함
+pub const GAS_FOR_RESOLVE_TRANSFER: Gas = Gas(15_000_000_000_000);
+// 수신자의 nft_on_transfer 에 최소한으로 보장하는 가스. 남는 가스는 모두 수신자에게 전달됨
+pub const GAS_FOR_NFT_ON_TRANSFER: Gas = Gas(25_000_000_000_000);
+// nft_transfer_call 자체의 실행과 Promise 생성에 필요한 가스
+pub const GAS_FOR_NFT_TRANSFER_CALL: Gas = Gas(25_000_000_000_000);
 
 pub trait NonFungibleTokenCore {
     fn nft_transfer(
@@ -40,7 +44,7 @@ trait NonFungibleTokenReceiver {
 }
 
 #[ext_contract(ext_self)]
-trait NonFungibleTokenResolver {
+pub trait NonFungibleTokenResolver {
 
     fn nft_resolve_transfer(
         &mut self,
@@ -48,6 +52,8 @@ trait NonFungibleTokenResolver {
         receiver_id: AccountId,
         token_id: TokenId,
         approved_account_ids: HashMap<AccountId, u64>,
+        approval_expires_at: HashMap<AccountId, u64>,
+        sender_id: AccountId,
     ) -> bool;
 }
 
@@ -62,10 +68,12 @@ impl NonFungibleTokenCore for Contract {
         approval_id: Option<u64>,
         memo: Option<String>
     ) {
-        // Transaction 서명을 위해 (보안)
+        // Transaction 서명을 위해 (보안). NEP-171 에 따라 정확히 1 yocto 만 받음
+        // 소유 이력 항목 등 늘어난 스토리지는 발신자의 스토리지 잔액에서 차감함
         assert_one_yocto();
         // 발신자
         let sender_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
 
         // 내부 함수 call - 구현함수
         let previous_token = self.internal_transfer(
@@ -75,10 +83,12 @@ impl NonFungibleTokenCore for Contract {
             approval_id,
             memo,
         );
+        self.internal_charge_storage(&sender_id, env::storage_usage().saturating_sub(initial_storage_usage));
 
         refund_approved_account_ids(
             previous_token.owner_id.clone(),
             &previous_token.approved_account_ids,
+            &previous_token.approval_expires_at,
         );
     }
 
@@ -93,7 +103,17 @@ impl NonFungibleTokenCore for Contract {
     ) -> PromiseOrValue<bool> {
         assert_one_yocto();
 
+        // nft_resolve_transfer 가 가스 부족으로 실패하면 토큰과 승인 목록을 되돌릴 수 없으므로 미리 확인
+        let required_gas = GAS_FOR_NFT_TRANSFER_CALL + GAS_FOR_NFT_ON_TRANSFER + GAS_FOR_RESOLVE_TRANSFER;
+        assert!(
+            env::prepaid_gas() >= required_gas,
+            "Attach at least {} gas to nft_transfer_call, {} was attached",
+            required_gas.0,
+            env::prepaid_gas().0,
+        );
+
         let sender_id = env::predecessor_account_id();
+        let initial_storage_usage = env::storage_usage();
         let previous_token = self.internal_transfer(
             &sender_id,
             &receiver_id,
@@ -101,11 +121,21 @@ impl NonFungibleTokenCore for Contract {
             approval_id,
             memo,
         );
-        // Question: - 콜백을 받기 위함?
+        // 수신자가 토큰을 되돌려 보낼 때 남는 이력 항목의 스토리지까지 미리 차감하고,
+        // 쓰지 않은 만큼은 nft_resolve_transfer 에서 돌려줌
+        let reserved_storage = self.internal_provenance_entry_storage(&token_id, &previous_token.owner_id);
+        self.internal_charge_storage(
+            &sender_id,
+            env::storage_usage().saturating_sub(initial_storage_usage) + reserved_storage,
+        );
+
+        // 수신자는 최소 GAS_FOR_NFT_ON_TRANSFER 에 더해 남는 가스를 모두 받고,
+        // nft_resolve_transfer 는 고정된 GAS_FOR_RESOLVE_TRANSFER 만 받습니다.
         ext_non_fungible_token_receiver::ext(receiver_id.clone())
             .with_static_gas(GAS_FOR_NFT_ON_TRANSFER)
+            .with_unused_gas_weight(1)
             .nft_on_transfer(
-                sender_id,
+                sender_id.clone(),
                 previous_token.owner_id.clone(),
                 token_id.clone(),
                 msg
@@ -113,11 +143,14 @@ impl NonFungibleTokenCore for Contract {
             .then(
                 Self::ext(env::current_account_id())
                     .with_static_gas(GAS_FOR_RESOLVE_TRANSFER)
+                    .with_unused_gas_weight(0)
                     .nft_resolve_transfer(
                         previous_token.owner_id,
                         receiver_id,
                         token_id,
                         previous_token.approved_account_ids,
+                        previous_token.approval_expires_at,
+                        sender_id,
                     )
             ).into()
     }
@@ -129,12 +162,30 @@ impl NonFungibleTokenCore for Contract {
         // tokens_by_id 컬렉션에 토큰 ID가 있는 경우
         if let Some(token) = self.tokens_by_id.get(&token_id) {
             let metadata = self.token_metadata_by_id.get(&token_id).unwrap();
+            let mint_seq = self.mint_seq_by_id.get(&token_id).unwrap();
+            let transfer_locked_until = self.internal_transfer_locked_until(&token_id).map(U64);
+            let drop_id = self.drop_by_token.get(&token_id);
+            let frozen_reason = self.frozen_tokens.get(&token_id).map(|frozen| frozen.reason);
 
             Some(JsonToken {
-                token_id,
+                token_id: token_id.clone(),
                 owner_id: token.owner_id,
+                issued_at_ms: metadata.issued_at.0 / 1_000_000,
+                mint_seq,
                 metadata,
                 approved_account_ids: token.approved_account_ids,
+                approval_expires_at: token
+                    .approval_expires_at
+                    .into_iter()
+                    .map(|(account_id, expires_at)| (account_id, U64(expires_at)))
+                    .collect(),
+                drop_id,
+                transfer_locked_until,
+                frozen: frozen_reason.is_some(),
+                frozen_reason,
+                level: self.token_levels.get(&token_id),
+                tier: self.token_tiers.get(&token_id),
+                ticket: self.tickets.get(&token_id),
             })
         } else {
             None
@@ -153,11 +204,17 @@ impl NonFungibleTokenResolver for Contract {
         receiver_id: AccountId,
         token_id: TokenId,
         approved_account_ids: HashMap<AccountId, u64>,
+        approval_expires_at: HashMap<AccountId, u64>,
+        sender_id: AccountId,
     ) -> bool {
+        // nft_transfer_call 에서 되돌림 이력 항목용으로 미리 차감한 스토리지
+        let reserved_storage = self.internal_provenance_entry_storage(&token_id, &owner_id);
+
         if let PromiseResult::Successful(value) = env::promise_result(0) {
             if let Ok(return_token) = near_sdk::serde_json::from_slice::<bool>(&value) {
                 if !return_token {
-                    refund_approved_account_ids(owner_id, &approved_account_ids);
+                    self.internal_credit_storage(&sender_id, reserved_storage);
+                    refund_approved_account_ids(owner_id, &approved_account_ids, &approval_expires_at);
                     return true;
                 }
             }
@@ -166,15 +223,19 @@ impl NonFungibleTokenResolver for Contract {
         let mut token = if let Some(token) = self.tokens_by_id.get(&token_id) {
             if token.owner_id != receiver_id {
                 // The token is not owner by the receiver anymore. Can't return it.
-                refund_approved_account_ids(owner_id, &approved_account_ids);
+                self.internal_credit_storage(&sender_id, reserved_storage);
+                refund_approved_account_ids(owner_id, &approved_account_ids, &approval_expires_at);
                 return true;
             }
             token
         } else {
-            refund_approved_account_ids(owner_id, &approved_account_ids);
+            self.internal_credit_storage(&sender_id, reserved_storage);
+            refund_approved_account_ids(owner_id, &approved_account_ids, &approval_expires_at);
             return true;
         };
 
+        let initial_storage_usage = env::storage_usage();
+
         log!("Return {} from @{} to @{}", token_id, receiver_id, owner_id);
 
         self.internal_remove_token_from_owner(&receiver_id, &token_id);
@@ -183,10 +244,27 @@ impl NonFungibleTokenResolver for Contract {
 
         token.owner_id = owner_id;
 
-        refund_approved_account_ids(receiver_id, &token.approved_account_ids);
+        refund_approved_account_ids(
+            receiver_id.clone(),
+            &token.approved_account_ids,
+            &token.approval_expires_at,
+        );
         token.approved_account_ids = approved_account_ids;
+        token.approval_expires_at = approval_expires_at;
 
         self.tokens_by_id.insert(&token_id, &token);
+        self.internal_record_provenance(&token_id, &token.owner_id, ProvenanceKind::Return, None);
+        let storage_used = env::storage_usage().saturating_sub(initial_storage_usage);
+        self.internal_credit_storage(&sender_id, reserved_storage.saturating_sub(storage_used));
+
+        EventLog::nep171(EventLogVariant::NftTransfer(vec![NftTransferLog {
+            authorized_id: None,
+            old_owner_id: receiver_id,
+            new_owner_id: token.owner_id.clone(),
+            token_ids: vec![token_id],
+            memo: None,
+        }]))
+        .emit();
 
         false
     }
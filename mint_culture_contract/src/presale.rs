@@ -0,0 +1,113 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, refund_deposit};
+
+pub type SalePhaseId = String;
+
+// 구독자 대상 프리세일 단계. 허용 목록은 머클 루트로만 저장함
+//
+// 리프: sha256("{account_id}:{allocation}")
+// 노드: sha256(작은 값 || 큰 값) - 두 자식을 바이트 순으로 정렬해서 이어 붙이므로 증명에 좌우 정보가 필요 없음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone)]
+#[serde(crate = "near_sdk::serde")]
+pub struct SalePhase {
+    pub merkle_root: Base64VecU8,
+    pub starts_at: U64,
+    pub ends_at: U64,
+    // 이 단계에서 민팅된 토큰이 속할 드롭
+    pub drop_id: Option<DropId>,
+    // 이 단계에서 민팅될 토큰 메타데이터
+    pub metadata: TokenMetadata,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 프리세일 단계 생성 또는 변경 (소유자만 가능)
+    #[payable]
+    pub fn set_sale_phase(&mut self, phase_id: SalePhaseId, phase: SalePhase) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert_eq!(
+            phase.merkle_root.0.len(),
+            32,
+            "merkle_root must be a base64-encoded sha256 hash (32 bytes)"
+        );
+        assert!(phase.starts_at.0 < phase.ends_at.0, "starts_at must be before ends_at");
+        if let Some(drop_id) = &phase.drop_id {
+            assert!(self.drops.get(drop_id).is_some(), "Drop {} does not exist", drop_id);
+        }
+        phase.metadata.assert_valid(&self.metadata_limits);
+        // 판매 단계에서 민팅할 토큰 ID 가 공개 시리즈 토큰 ID 와 겹치지 않아야 함
+        self.assert_not_reveal_token_id(&format!("{}-", phase_id));
+
+        self.sale_phases.insert(&phase_id, &phase);
+    }
+
+    pub fn get_sale_phase(&self, phase_id: SalePhaseId) -> Option<SalePhase> {
+        self.sale_phases.get(&phase_id)
+    }
+
+    // 계정이 프리세일 단계에서 이미 민팅한 토큰 수
+    pub fn get_presale_claimed(&self, phase_id: SalePhaseId, account_id: AccountId) -> u32 {
+        self.presale_claims.get(&(phase_id, account_id)).unwrap_or(0)
+    }
+
+    // 허용 목록에 있는 계정이 증명과 배정 수량을 제출하고 단계의 메타데이터로 토큰 하나를 민팅합니다.
+    // 배정 수량만큼 여러 번 호출할 수 있으며 민팅된 토큰 ID를 반환합니다.
+    #[payable]
+    pub fn nft_presale_mint(
+        &mut self,
+        phase_id: SalePhaseId,
+        allocation: u32,
+        proof: Vec<Base64VecU8>,
+    ) -> TokenId {
+        let phase = self.sale_phases.get(&phase_id).expect("Sale phase does not exist");
+        let now = env::block_timestamp();
+        assert!(
+            now >= phase.starts_at.0 && now < phase.ends_at.0,
+            "Sale phase {} is not active",
+            phase_id
+        );
+
+        let account_id = env::predecessor_account_id();
+        assert!(
+            verify_merkle_proof(&presale_leaf(&account_id, allocation), &proof, &phase.merkle_root.0),
+            "Invalid allowlist proof"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+
+        let key = (phase_id.clone(), account_id.clone());
+        let claimed = self.presale_claims.get(&key).unwrap_or(0);
+        assert!(
+            claimed < allocation,
+            "Account {} already claimed its allocation of {} tokens",
+            account_id,
+            allocation
+        );
+        self.presale_claims.insert(&key, &(claimed + 1));
+
+        let token_id = format!("{}-{}", phase_id, self.next_mint_seq);
+        self.internal_mint(token_id.clone(), phase.metadata, account_id, phase.drop_id);
+
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+        token_id
+    }
+}
+
+// 허용 목록의 리프 해시
+pub(crate) fn presale_leaf(account_id: &AccountId, allocation: u32) -> Vec<u8> {
+    env::sha256(format!("{}:{}", account_id, allocation).as_bytes())
+}
+
+// 두 자식 노드를 정렬해서 부모 노드 해시를 계산
+pub(crate) fn merkle_parent(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let (left, right) = if a <= b { (a, b) } else { (b, a) };
+    env::sha256(&[left, right].concat())
+}
+
+pub(crate) fn verify_merkle_proof(leaf: &[u8], proof: &[Base64VecU8], root: &[u8]) -> bool {
+    let computed = proof
+        .iter()
+        .fold(leaf.to_vec(), |node, sibling| merkle_parent(&node, &sibling.0));
+    computed == root
+}
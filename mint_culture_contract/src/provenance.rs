@@ -0,0 +1,95 @@
+use crate::*;
+use crate::internal::hash_token_id;
+use std::mem::size_of;
+
+// 스토리지 레코드 하나에 추가로 매겨지는 바이트 수
+const STORAGE_BYTES_PER_RECORD: usize = 40;
+
+// 소유 이력이 기록된 동작
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum ProvenanceKind {
+    Mint,
+    Transfer,
+    // nft_transfer_call 의 수신자가 토큰을 되돌려 보냄
+    Return,
+    Burn,
+}
+
+// 토큰 소유 이력의 한 항목
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct ProvenanceEntry {
+    // 이 동작 이후의 소유자. 소각인 경우 소각한 소유자
+    pub owner_id: AccountId,
+    pub timestamp: U64,
+    pub block_height: U64,
+    pub kind: ProvenanceKind,
+    // 판매 가격 (yoctoNEAR). 알 수 없으면 None
+    pub price: Option<U128>,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 토큰의 소유 이력 (오래된 순). 소각된 토큰의 이력도 남아 있음
+    pub fn nft_token_history(&self, token_id: TokenId, from_index: Option<U128>, limit: Option<u64>) -> Vec<ProvenanceEntry> {
+        let history = if let Some(history) = self.token_history.get(&token_id) {
+            history
+        } else {
+            return vec![];
+        };
+
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        history.iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .collect()
+    }
+}
+
+impl Contract {
+    // 토큰 이력에 항목을 추가합니다. 스토리지 비용은 동작을 시작한 계정이 냅니다.
+    // NEP-171 전송에서는 스토리지 잔액(storage_deposit)에서, 그 밖에는 첨부한 보증금에서 차감합니다.
+    pub(crate) fn internal_record_provenance(
+        &mut self,
+        token_id: &TokenId,
+        owner_id: &AccountId,
+        kind: ProvenanceKind,
+        price: Option<U128>,
+    ) {
+        let mut history = self.token_history.get(token_id).unwrap_or_else(|| {
+            Vector::new(Self::token_history_prefix(token_id))
+        });
+        history.push(&ProvenanceEntry {
+            owner_id: owner_id.clone(),
+            timestamp: U64(env::block_timestamp()),
+            block_height: U64(env::block_height()),
+            kind,
+            price,
+        });
+        self.token_history.insert(token_id, &history);
+    }
+
+    // owner_id 로 이력 항목 하나를 추가할 때 늘어나는 스토리지 (바이트)
+    pub(crate) fn internal_provenance_entry_storage(&self, token_id: &TokenId, owner_id: &AccountId) -> u64 {
+        let entry = ProvenanceEntry {
+            owner_id: owner_id.clone(),
+            timestamp: U64(0),
+            block_height: U64(0),
+            kind: ProvenanceKind::Return,
+            price: None,
+        };
+        // 레코드 오버헤드 + 키 (Vector 접두사와 u64 인덱스) + 값
+        let key_len = Self::token_history_prefix(token_id).len() + size_of::<u64>();
+        (STORAGE_BYTES_PER_RECORD + key_len + entry.try_to_vec().unwrap().len()) as u64
+    }
+
+    fn token_history_prefix(token_id: &TokenId) -> Vec<u8> {
+        StorageKey::TokenHistoryInner {
+            token_id_hash: hash_token_id(token_id),
+        }
+        .try_to_vec()
+        .unwrap()
+    }
+}
@@ -0,0 +1,248 @@
+use crate::*;
+use crate::internal::{
+    assert_at_least_one_yocto, assert_one_yocto, hash_token_id, refund_approved_account_ids, refund_deposit,
+};
+
+// 복구 요청 후 실행할 수 있을 때까지 기다려야 하는 기본 시간 (7일, 나노초)
+pub const DEFAULT_RECOVERY_DELAY: u64 = 7 * 24 * 60 * 60 * 1_000_000_000;
+
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+#[serde(rename_all = "snake_case")]
+pub enum RecoveryStatus {
+    Pending,
+    Vetoed,
+    Cancelled,
+    Executed,
+}
+
+// 토큰 복구 요청 기록. 토큰마다 요청 순서대로 모두 남음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RecoveryRecord {
+    pub requested_by: AccountId,
+    pub previous_owner_id: AccountId,
+    pub new_owner_id: AccountId,
+    // 오프체인 증빙 자료의 sha256 해시
+    pub evidence_hash: Base64VecU8,
+    pub requested_at: U64,
+    // 이 시각부터 실행할 수 있고, 그 전까지는 소유자가 거부할 수 있음
+    pub executable_at: U64,
+    pub status: RecoveryStatus,
+    pub resolved_at: Option<U64>,
+}
+
+impl RecoveryRecord {
+    fn log(&self, token_id: &TokenId) -> RecoveryLog {
+        RecoveryLog {
+            token_id: token_id.clone(),
+            previous_owner_id: self.previous_owner_id.clone(),
+            new_owner_id: self.new_owner_id.clone(),
+            evidence_hash: self.evidence_hash.clone(),
+            executable_at: self.executable_at,
+        }
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // 지갑을 잃어버린 소유자를 위해 토큰 복구를 요청합니다 (소유자만 가능)
+    #[payable]
+    pub fn request_recovery(&mut self, token_id: TokenId, new_owner_id: AccountId, evidence_hash: Base64VecU8) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert_eq!(
+            evidence_hash.0.len(),
+            32,
+            "evidence_hash must be a base64-encoded sha256 hash (32 bytes)"
+        );
+
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_ne!(token.owner_id, new_owner_id, "The token is already owned by {}", new_owner_id);
+        assert!(
+            self.internal_pending_recovery(&token_id).is_none(),
+            "Token {} already has a pending recovery",
+            token_id
+        );
+
+        let now = env::block_timestamp();
+        let record = RecoveryRecord {
+            requested_by: env::predecessor_account_id(),
+            previous_owner_id: token.owner_id,
+            new_owner_id,
+            evidence_hash,
+            requested_at: U64(now),
+            executable_at: U64(now + self.recovery_delay),
+            status: RecoveryStatus::Pending,
+            resolved_at: None,
+        };
+
+        let mut records = self.recovery_records.get(&token_id).unwrap_or_else(|| {
+            Vector::new(
+                StorageKey::RecoveryRecordsInner {
+                    token_id_hash: hash_token_id(&token_id),
+                }
+                .try_to_vec()
+                .unwrap(),
+            )
+        });
+        records.push(&record);
+        self.recovery_records.insert(&token_id, &records);
+
+        EventLog::mint_culture(EventLogVariant::RecoveryRequest(vec![record.log(&token_id)])).emit();
+    }
+
+    // 현재 소유자가 대기 중인 복구 요청을 거부합니다.
+    #[payable]
+    pub fn veto_recovery(&mut self, token_id: TokenId) {
+        assert_one_yocto();
+        let (index, record) = self
+            .internal_pending_recovery(&token_id)
+            .expect("No pending recovery for the token");
+
+        assert_eq!(
+            env::predecessor_account_id(),
+            record.previous_owner_id,
+            "Only the current token owner can veto the recovery"
+        );
+        assert!(
+            env::block_timestamp() < record.executable_at.0,
+            "The veto period of the recovery has ended"
+        );
+
+        let record = self.internal_resolve_recovery(&token_id, index, record, RecoveryStatus::Vetoed);
+        EventLog::mint_culture(EventLogVariant::RecoveryVeto(vec![record.log(&token_id)])).emit();
+    }
+
+    // 잘못 요청한 복구를 취소합니다 (소유자만 가능)
+    #[payable]
+    pub fn cancel_recovery(&mut self, token_id: TokenId) {
+        assert_one_yocto();
+        self.assert_owner();
+        let (index, record) = self
+            .internal_pending_recovery(&token_id)
+            .expect("No pending recovery for the token");
+
+        let record = self.internal_resolve_recovery(&token_id, index, record, RecoveryStatus::Cancelled);
+        EventLog::mint_culture(EventLogVariant::RecoveryCancel(vec![record.log(&token_id)])).emit();
+    }
+
+    // 거부 기간이 지난 복구 요청을 실행하여 토큰을 새 소유자에게 전송합니다 (소유자만 가능)
+    #[payable]
+    pub fn execute_recovery(&mut self, token_id: TokenId) {
+        assert_at_least_one_yocto();
+        self.assert_owner();
+        let (index, record) = self
+            .internal_pending_recovery(&token_id)
+            .expect("No pending recovery for the token");
+
+        assert!(
+            env::block_timestamp() >= record.executable_at.0,
+            "The recovery can be executed after {}",
+            record.executable_at.0
+        );
+
+        // 요청 이후 토큰 소유자가 바뀌었다면 복구할 수 없음
+        let token = self.tokens_by_id.get(&token_id).expect("No token");
+        assert_eq!(
+            token.owner_id, record.previous_owner_id,
+            "The token owner changed after the recovery was requested"
+        );
+
+        let initial_storage_usage = env::storage_usage();
+        let previous_token = self.internal_recovery_transfer(&record.new_owner_id, &token_id);
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+        refund_approved_account_ids(
+            previous_token.owner_id,
+            &previous_token.approved_account_ids,
+            &previous_token.approval_expires_at,
+        );
+
+        let record = self.internal_resolve_recovery(&token_id, index, record, RecoveryStatus::Executed);
+        EventLog::mint_culture(EventLogVariant::RecoveryExecute(vec![record.log(&token_id)])).emit();
+    }
+
+    // 토큰의 복구 요청 기록 (오래된 순)
+    pub fn get_recovery_records(&self, token_id: TokenId, from_index: Option<U128>, limit: Option<u64>) -> Vec<RecoveryRecord> {
+        let records = if let Some(records) = self.recovery_records.get(&token_id) {
+            records
+        } else {
+            return vec![];
+        };
+
+        let start = u128::from(from_index.unwrap_or(U128(0)));
+        records.iter()
+            .skip(start as usize)
+            .take(limit.unwrap_or(50) as usize)
+            .collect()
+    }
+
+    pub fn get_recovery_delay(&self) -> U64 {
+        U64(self.recovery_delay)
+    }
+
+    // 복구 거부 기간 변경 (소유자만 가능). 이미 요청된 복구에는 적용되지 않음
+    #[payable]
+    pub fn set_recovery_delay(&mut self, recovery_delay: U64) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.recovery_delay = recovery_delay.0;
+    }
+}
+
+impl Contract {
+    // 복구 전송. 도난이나 분쟁으로 동결되거나 잠긴 토큰, 차단된 이전 소유자의 토큰도 되찾을 수 있도록
+    // 동결, 전송 잠금, 보내는 계정 차단은 확인하지 않고 받는 계정만 확인합니다.
+    fn internal_recovery_transfer(&mut self, receiver_id: &AccountId, token_id: &TokenId) -> Token {
+        self.assert_migrated();
+        let token = self.tokens_by_id.get(token_id).expect("No Exist Token");
+
+        // 업적 배지와 입장 처리된 입장권은 복구로도 옮길 수 없음
+        self.assert_not_soulbound(token_id);
+        self.assert_ticket_not_redeemed(token_id);
+        self.assert_not_blocked(receiver_id);
+        self.assert_within_held_limit(receiver_id, token_id);
+        assert_ne!(
+            &token.owner_id, receiver_id,
+            "토큰 소유자와 토큰 수신자는 달라야 합니다."
+        );
+
+        // 이전 소유자가 걸어둔 전송 제안은 무효가 됨
+        self.transfer_offers.remove(token_id);
+
+        self.internal_move_token(
+            token,
+            receiver_id,
+            token_id,
+            Some(env::predecessor_account_id()),
+            Some("recovery".to_string()),
+        )
+    }
+
+    // 대기 중인 복구 요청과 그 위치. 대기 중인 요청은 항상 마지막 기록임
+    fn internal_pending_recovery(&self, token_id: &TokenId) -> Option<(u64, RecoveryRecord)> {
+        let records = self.recovery_records.get(token_id)?;
+        let index = records.len().checked_sub(1)?;
+        let record = records.get(index)?;
+        if record.status == RecoveryStatus::Pending {
+            Some((index, record))
+        } else {
+            None
+        }
+    }
+
+    fn internal_resolve_recovery(
+        &mut self,
+        token_id: &TokenId,
+        index: u64,
+        mut record: RecoveryRecord,
+        status: RecoveryStatus,
+    ) -> RecoveryRecord {
+        record.status = status;
+        record.resolved_at = Some(U64(env::block_timestamp()));
+
+        let mut records = self.recovery_records.get(token_id).unwrap();
+        records.replace(index, &record);
+        record
+    }
+}
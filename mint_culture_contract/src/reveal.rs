@@ -0,0 +1,193 @@
+use crate::*;
+use crate::internal::{assert_at_least_one_yocto, assert_one_yocto, charge_price_and_storage, refund_deposit};
+use crate::presale::verify_merkle_proof;
+
+pub type RevealSeriesId = String;
+
+// 작품이 완성되기 전에 판매하는 시리즈. 공개 전까지 토큰은 대체 메타데이터를 가짐
+//
+// metadata_root 는 최종 메타데이터 size 개 목록의 머클 루트
+// 리프: sha256(borsh((token_id, metadata))) - metadata.issued_at 은 0, legacy_issued_at 은 None 으로 두고 계산
+// 토큰 ID 는 민팅 순서대로 "{series_id}-0" 부터 "{series_id}-{size - 1}" 까지 부여됨
+// "{series_id}-" 로 시작하는 토큰 ID 는 이 시리즈에만 쓸 수 있도록 예약됨
+// 노드는 프리세일 허용 목록과 같은 방식으로 계산함
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RevealSeries {
+    pub placeholder: TokenMetadata,
+    pub metadata_root: Base64VecU8,
+    // 커밋된 최종 메타데이터 수. 이보다 많이 민팅할 수 없음
+    pub size: u32,
+    // 토큰 하나의 가격 (yoctoNEAR). 컨트랙트 소유자에게 전송됨
+    pub price: U128,
+    #[serde(default)]
+    pub minted: u32,
+    #[serde(default)]
+    pub revealed: u32,
+}
+
+// reveal 에 넘기는 토큰 하나의 최종 메타데이터와 머클 증명
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct RevealEntry {
+    pub token_id: TokenId,
+    pub metadata: TokenMetadata,
+    pub proof: Vec<Base64VecU8>,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 공개 시리즈 생성 (소유자만 가능)
+    #[payable]
+    pub fn create_reveal_series(
+        &mut self,
+        series_id: RevealSeriesId,
+        placeholder: TokenMetadata,
+        metadata_root: Base64VecU8,
+        size: u32,
+        price: U128,
+    ) {
+        assert_one_yocto();
+        self.assert_owner();
+        placeholder.assert_valid(&self.metadata_limits);
+        assert!(size > 0, "A reveal series needs at least one token");
+        assert_eq!(
+            metadata_root.0.len(),
+            32,
+            "metadata_root must be a base64-encoded sha256 hash (32 bytes)"
+        );
+        // 다른 생성기의 토큰 ID 가 시리즈 토큰 ID 와 겹치지 않아야 함
+        let prefix = format!("{}-", series_id);
+        let generator_ids = self
+            .claim_templates
+            .keys()
+            .chain(self.crafting_recipes.keys())
+            .chain(self.mystery_boxes.keys())
+            .chain(self.sale_phases.keys());
+        for id in generator_ids {
+            assert!(
+                id != series_id && !id.starts_with(&prefix),
+                "Token IDs of {} would collide with reveal series {}",
+                id,
+                series_id
+            );
+        }
+
+        let series = RevealSeries {
+            placeholder,
+            metadata_root,
+            size,
+            price,
+            minted: 0,
+            revealed: 0,
+        };
+        assert!(
+            self.reveal_series.insert(&series_id, &series).is_none(),
+            "Reveal series {} already exists",
+            series_id
+        );
+    }
+
+    pub fn get_reveal_series(&self, series_id: RevealSeriesId) -> Option<RevealSeries> {
+        self.reveal_series.get(&series_id)
+    }
+
+    // 대체 메타데이터로 시리즈의 다음 토큰을 구매합니다. 가격과 스토리지 비용을 첨부해야 하며 민팅된 토큰 ID를 반환합니다.
+    #[payable]
+    pub fn nft_mint_unrevealed(&mut self, series_id: RevealSeriesId, receiver_id: AccountId) -> TokenId {
+        let initial_storage_usage = env::storage_usage();
+
+        let mut series = self.reveal_series.get(&series_id).expect("Reveal series does not exist");
+        assert!(series.minted < series.size, "Reveal series {} is sold out", series_id);
+        let token_id = format!("{}-{}", series_id, series.minted);
+        series.minted += 1;
+        let placeholder = series.placeholder.clone();
+        self.reveal_series.insert(&series_id, &series);
+
+        self.unrevealed_series_by_token.insert(&token_id, &series_id);
+        self.internal_mint_with_price(token_id.clone(), placeholder, receiver_id, None, Some(series.price));
+
+        charge_price_and_storage(env::storage_usage() - initial_storage_usage, series.price.0, self.owner_id.clone());
+        token_id
+    }
+
+    // 최종 메타데이터를 검증하고 대체 메타데이터와 바꿉니다 (소유자만 가능). 여러 번에 나눠 호출할 수 있습니다.
+    #[payable]
+    pub fn reveal(&mut self, series_id: RevealSeriesId, metadata_list: Vec<RevealEntry>) {
+        assert_at_least_one_yocto();
+        self.assert_owner();
+        let initial_storage_usage = env::storage_usage();
+        let mut series = self.reveal_series.get(&series_id).expect("Reveal series does not exist");
+
+        let mut token_ids = vec![];
+        for entry in metadata_list {
+            assert_eq!(
+                self.unrevealed_series_by_token.get(&entry.token_id).as_ref(),
+                Some(&series_id),
+                "Token {} is not an unrevealed token of series {}",
+                entry.token_id,
+                series_id
+            );
+
+            let mut metadata = entry.metadata;
+            metadata.issued_at = U64(0);
+            metadata.legacy_issued_at = None;
+            let leaf = env::sha256(&(&entry.token_id, &metadata).try_to_vec().unwrap());
+            assert!(
+                verify_merkle_proof(&leaf, &entry.proof, &series.metadata_root.0),
+                "Metadata of token {} does not match the committed metadata",
+                entry.token_id
+            );
+            metadata.assert_valid(&self.metadata_limits);
+
+            self.internal_replace_token_metadata(&entry.token_id, metadata);
+            self.unrevealed_series_by_token.remove(&entry.token_id);
+            series.revealed += 1;
+            token_ids.push(entry.token_id);
+        }
+        self.reveal_series.insert(&series_id, &series);
+
+        // 최종 메타데이터가 대체 메타데이터보다 커진 만큼의 스토리지 비용
+        refund_deposit(env::storage_usage().saturating_sub(initial_storage_usage));
+
+        EventLog::mint_culture(EventLogVariant::TokenMetadataUpdate(vec![TokenMetadataUpdateLog {
+            token_ids,
+            memo: Some(format!("reveal:{}", series_id)),
+        }]))
+        .emit();
+    }
+}
+
+impl Contract {
+    // 토큰의 메타데이터를 바꿉니다. 발행 일자는 유지되고 스트리머별 보유 수도 갱신됩니다.
+    // 검증은 호출하는 쪽에서 해야 합니다.
+    pub(crate) fn internal_replace_token_metadata(&mut self, token_id: &TokenId, metadata: TokenMetadata) {
+        let owner_id = self.tokens_by_id.get(token_id).expect("No token").owner_id;
+        let previous = self.token_metadata_by_id.get(token_id).expect("No token");
+
+        let mut metadata = metadata;
+        metadata.issued_at = previous.issued_at;
+        metadata.legacy_issued_at = previous.legacy_issued_at;
+
+        self.internal_update_held_per_streamer(&owner_id, token_id, false);
+        self.token_metadata_by_id.insert(token_id, &metadata);
+        self.internal_update_held_per_streamer(&owner_id, token_id, true);
+    }
+
+    // "{series_id}-" 로 시작하는 토큰 ID 는 그 공개 시리즈의 민팅에만 쓸 수 있음
+    // 시리즈 민팅은 unrevealed_series_by_token 에 먼저 표시한 뒤 internal_mint 를 호출함
+    pub(crate) fn assert_not_reveal_token_id(&self, token_id: &str) {
+        if self.reveal_series.is_empty() || self.unrevealed_series_by_token.contains_key(&token_id.to_string()) {
+            return;
+        }
+        for (index, _) in token_id.match_indices('-') {
+            let series_id = &token_id[..index];
+            assert!(
+                self.reveal_series.get(&series_id.to_string()).is_none(),
+                "Token IDs starting with {}- are reserved for reveal series {}",
+                series_id,
+                series_id
+            );
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::*;
+use crate::internal::assert_one_yocto;
+
+// 스토리지 잔액 항목 하나가 차지할 수 있는 최대 바이트 수 (레코드 오버헤드 40 + 키 + u128 값, 계정 ID 는 최대 64바이트)
+const STORAGE_BALANCE_ENTRY_BYTES: u64 = 40 + 1 + 4 + 64 + 16;
+
+// NEP-145 스토리지 잔액. available 은 total 에서 등록 최소 금액을 뺀 금액
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalance {
+    pub total: U128,
+    pub available: U128,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+#[serde(crate = "near_sdk::serde")]
+pub struct StorageBalanceBounds {
+    pub min: U128,
+    pub max: Option<U128>,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 첨부한 금액을 계정의 스토리지 잔액에 넣습니다. NEP-171 전송처럼 보증금을 받을 수 없는 동작의
+    // 스토리지 비용은 그 동작을 시작한 계정의 스토리지 잔액에서 차감됩니다.
+    #[payable]
+    pub fn storage_deposit(&mut self, account_id: Option<AccountId>, registration_only: Option<bool>) -> StorageBalance {
+        let amount = env::attached_deposit();
+        let account_id = account_id.unwrap_or_else(env::predecessor_account_id);
+        let min_balance = self.storage_balance_bounds().min.0;
+        let registration_only = registration_only.unwrap_or(false);
+
+        let (balance, refund) = match self.storage_balances.get(&account_id) {
+            // 이미 등록된 계정은 registration_only 이면 전액 환불
+            Some(balance) if registration_only => (balance, amount),
+            Some(balance) => (balance + amount, 0),
+            None => {
+                assert!(
+                    amount >= min_balance,
+                    "The attached deposit is less than the minimum storage balance {}",
+                    min_balance
+                );
+                if registration_only {
+                    (min_balance, amount - min_balance)
+                } else {
+                    (amount, 0)
+                }
+            }
+        };
+        self.storage_balances.insert(&account_id, &balance);
+        if refund > 0 {
+            Promise::new(env::predecessor_account_id()).transfer(refund);
+        }
+
+        self.internal_storage_balance(balance)
+    }
+
+    // 스토리지 잔액 중 사용 가능한 금액을 돌려받습니다. amount 가 없으면 전부 돌려받습니다.
+    #[payable]
+    pub fn storage_withdraw(&mut self, amount: Option<U128>) -> StorageBalance {
+        assert_one_yocto();
+        let account_id = env::predecessor_account_id();
+        let balance = self
+            .storage_balances
+            .get(&account_id)
+            .unwrap_or_else(|| panic!("Account {} is not registered", account_id));
+        let available = balance - self.storage_balance_bounds().min.0;
+        let amount = amount.map(|amount| amount.0).unwrap_or(available);
+        assert!(
+            amount <= available,
+            "Cannot withdraw {} yoctoNEAR, only {} is available",
+            amount,
+            available
+        );
+
+        let balance = balance - amount;
+        self.storage_balances.insert(&account_id, &balance);
+        if amount > 0 {
+            Promise::new(account_id).transfer(amount);
+        }
+
+        self.internal_storage_balance(balance)
+    }
+
+    pub fn storage_balance_bounds(&self) -> StorageBalanceBounds {
+        StorageBalanceBounds {
+            min: U128(env::storage_byte_cost() * Balance::from(STORAGE_BALANCE_ENTRY_BYTES)),
+            max: None,
+        }
+    }
+
+    pub fn storage_balance_of(&self, account_id: AccountId) -> Option<StorageBalance> {
+        self.storage_balances
+            .get(&account_id)
+            .map(|balance| self.internal_storage_balance(balance))
+    }
+}
+
+impl Contract {
+    fn internal_storage_balance(&self, balance: Balance) -> StorageBalance {
+        StorageBalance {
+            total: U128(balance),
+            available: U128(balance - self.storage_balance_bounds().min.0),
+        }
+    }
+
+    // 계정의 스토리지 잔액에서 storage_used 바이트의 비용을 차감합니다.
+    pub(crate) fn internal_charge_storage(&mut self, account_id: &AccountId, storage_used: u64) {
+        if storage_used == 0 {
+            return;
+        }
+        let cost = env::storage_byte_cost() * Balance::from(storage_used);
+        let balance = self.storage_balances.get(account_id).unwrap_or(0);
+        let available = balance.saturating_sub(self.storage_balance_bounds().min.0);
+        assert!(
+            cost <= available,
+            "Account {} needs {} yoctoNEAR of prepaid storage (storage_deposit), {} is available",
+            account_id,
+            cost,
+            available
+        );
+        self.storage_balances.insert(account_id, &(balance - cost));
+    }
+
+    // 미리 차감한 스토리지 중 쓰지 않은 bytes 만큼 계정의 스토리지 잔액으로 돌려줍니다.
+    // 그 사이 등록이 없어진 계정이면 돌려주지 않습니다.
+    pub(crate) fn internal_credit_storage(&mut self, account_id: &AccountId, bytes: u64) {
+        if bytes == 0 {
+            return;
+        }
+        if let Some(balance) = self.storage_balances.get(account_id) {
+            let amount = env::storage_byte_cost() * Balance::from(bytes);
+            self.storage_balances.insert(account_id, &(balance + amount));
+        }
+    }
+}
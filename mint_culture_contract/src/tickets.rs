@@ -0,0 +1,107 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, refund_deposit};
+
+// 팬미팅 등 행사 입장권 정보
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct Ticket {
+    pub event_id: String,
+    pub section: String,
+    pub seat: Option<String>,
+    // 입장 가능한 시간 (블록 타임스탬프, 나노초). starts_at 이상 expires_at 미만
+    pub starts_at: U64,
+    pub expires_at: U64,
+    // 입장 처리되었는지 여부. 입장 처리된 입장권은 전송할 수 없음
+    #[serde(default)]
+    pub redeemed: bool,
+}
+
+#[near_bindgen]
+impl Contract {
+    // 입장 처리를 할 수 있는 현장 스캐너 계정 추가 (소유자만 가능)
+    #[payable]
+    pub fn add_venue_scanner(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.venue_scanners.insert(&account_id);
+    }
+
+    // 현장 스캐너 계정 제거 (소유자만 가능)
+    #[payable]
+    pub fn remove_venue_scanner(&mut self, account_id: AccountId) {
+        assert_one_yocto();
+        self.assert_owner();
+        self.venue_scanners.remove(&account_id);
+    }
+
+    pub fn get_venue_scanners(&self) -> Vec<AccountId> {
+        self.venue_scanners.to_vec()
+    }
+
+    // 입장권 토큰 민팅 (소유자만 가능)
+    #[payable]
+    pub fn nft_mint_ticket(&mut self, token_id: TokenId, metadata: TokenMetadata, receiver_id: AccountId, ticket: Ticket) {
+        self.assert_owner();
+        assert!(!ticket.event_id.is_empty(), "Ticket event_id must not be empty");
+        assert!(
+            ticket.starts_at.0 < ticket.expires_at.0,
+            "Ticket starts_at must be before expires_at"
+        );
+        assert!(!ticket.redeemed, "A new ticket must not be redeemed");
+
+        let initial_storage_usage = env::storage_usage();
+        self.tickets.insert(&token_id, &ticket);
+        self.internal_mint(token_id, metadata, receiver_id, None);
+        refund_deposit(env::storage_usage() - initial_storage_usage);
+    }
+
+    pub fn get_ticket(&self, token_id: TokenId) -> Option<Ticket> {
+        self.tickets.get(&token_id)
+    }
+
+    // 현장 스캐너가 입장권을 입장 처리합니다. 입장 가능한 시간 안에 한 번만 할 수 있습니다.
+    pub fn check_in(&mut self, token_id: TokenId) {
+        let scanner_id = env::predecessor_account_id();
+        assert!(
+            self.venue_scanners.contains(&scanner_id),
+            "Only a venue scanner can check in tickets"
+        );
+
+        let mut ticket = self
+            .tickets
+            .get(&token_id)
+            .unwrap_or_else(|| panic!("Token {} is not a ticket", token_id));
+        assert!(!ticket.redeemed, "Ticket {} has already been checked in", token_id);
+        let now = env::block_timestamp();
+        assert!(
+            now >= ticket.starts_at.0 && now < ticket.expires_at.0,
+            "Ticket {} is not valid at this time",
+            token_id
+        );
+        self.assert_not_frozen(&token_id);
+
+        ticket.redeemed = true;
+        self.tickets.insert(&token_id, &ticket);
+
+        let owner_id = self.tokens_by_id.get(&token_id).expect("No token").owner_id;
+        EventLog::mint_culture(EventLogVariant::TicketCheckIn(vec![TicketCheckInLog {
+            token_id,
+            owner_id,
+            event_id: ticket.event_id,
+            scanner_id,
+        }]))
+        .emit();
+    }
+}
+
+impl Contract {
+    pub(crate) fn assert_ticket_not_redeemed(&self, token_id: &TokenId) {
+        if let Some(ticket) = self.tickets.get(token_id) {
+            assert!(
+                !ticket.redeemed,
+                "Ticket {} has been checked in and cannot be transferred",
+                token_id
+            );
+        }
+    }
+}
@@ -0,0 +1,134 @@
+use crate::*;
+use crate::internal::assert_one_yocto;
+
+// 토큰 메타데이터 검증에 사용하는 제한값. 길이는 모두 바이트 단위
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize, Clone, Debug)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MetadataLimits {
+    pub max_streamer_name_len: u32,
+    pub max_title_len: u32,
+    pub max_description_len: u32,
+    pub max_media_len: u32,
+    // media URL이 시작할 수 있는 스킴 (예: "ipfs://")
+    pub allowed_media_schemes: Vec<String>,
+    // 일련번호는 정확히 이 길이의 숫자로만 구성되어야 함
+    pub serial_number_len: u32,
+}
+
+impl Default for MetadataLimits {
+    fn default() -> Self {
+        Self {
+            max_streamer_name_len: 64,
+            max_title_len: 128,
+            max_description_len: 1024,
+            max_media_len: 512,
+            allowed_media_schemes: vec![
+                "ipfs://".to_string(),
+                "https://".to_string(),
+                "ar://".to_string(),
+            ],
+            serial_number_len: 16,
+        }
+    }
+}
+
+impl MetadataLimits {
+    pub(crate) fn assert_valid(&self) {
+        assert!(
+            !self.allowed_media_schemes.is_empty(),
+            "At least one media scheme must be allowed"
+        );
+        for scheme in &self.allowed_media_schemes {
+            assert!(
+                scheme.len() > 3 && scheme.ends_with("://"),
+                "Invalid media scheme {}, expected a value like \"ipfs://\"",
+                scheme
+            );
+        }
+        assert!(self.serial_number_len > 0, "serial_number_len must be positive");
+    }
+
+    // 미디어 URL의 길이와 스킴, 미디어 해시 길이를 검사
+    pub(crate) fn assert_valid_media(&self, media: &str, media_hash: Option<&Base64VecU8>) {
+        assert_max_len("media", media, self.max_media_len);
+        let scheme = self
+            .allowed_media_schemes
+            .iter()
+            .find(|scheme| media.starts_with(scheme.as_str()))
+            .unwrap_or_else(|| {
+                panic!(
+                    "TokenMetadata.media must start with one of {:?}, got {:?}",
+                    self.allowed_media_schemes, media
+                )
+            });
+        assert!(
+            media.len() > scheme.len(),
+            "TokenMetadata.media must contain a location after {}",
+            scheme
+        );
+
+        if let Some(media_hash) = media_hash {
+            assert_eq!(
+                media_hash.0.len(),
+                32,
+                "TokenMetadata.media_hash must be a base64-encoded sha256 hash (32 bytes)"
+            );
+        }
+    }
+}
+
+// 필수 문자열 필드가 비어있지 않고 최대 길이를 넘지 않는지 검사
+fn assert_required_field(field: &str, value: &str, max_len: u32) {
+    assert!(!value.trim().is_empty(), "TokenMetadata.{} must not be empty", field);
+    assert_max_len(field, value, max_len);
+}
+
+fn assert_max_len(field: &str, value: &str, max_len: u32) {
+    assert!(
+        value.len() <= max_len as usize,
+        "TokenMetadata.{} is {} bytes long, the maximum is {} bytes",
+        field,
+        value.len(),
+        max_len
+    );
+}
+
+impl TokenMetadata {
+    // 민팅 및 메타데이터 변경 시 항상 호출해야 함
+    pub(crate) fn assert_valid(&self, limits: &MetadataLimits) {
+        assert_required_field("streamer_name", &self.streamer_name, limits.max_streamer_name_len);
+        assert_required_field("title", &self.title, limits.max_title_len);
+
+        if let Some(description) = &self.description {
+            assert_max_len("description", description, limits.max_description_len);
+        }
+
+        assert!(
+            self.serial_number.len() == limits.serial_number_len as usize
+                && self.serial_number.bytes().all(|b| b.is_ascii_digit()),
+            "TokenMetadata.serial_number must be exactly {} digits, got {:?}",
+            limits.serial_number_len,
+            self.serial_number
+        );
+
+        limits.assert_valid_media(&self.media, self.media_hash.as_ref());
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // 토큰 메타데이터 검증 제한값 조회
+    pub fn get_metadata_limits(&self) -> MetadataLimits {
+        self.metadata_limits.clone()
+    }
+
+    // 토큰 메타데이터 검증 제한값 변경 (소유자만 가능)
+    #[payable]
+    pub fn set_metadata_limits(&mut self, limits: MetadataLimits) {
+        assert_one_yocto();
+        self.assert_owner();
+        limits.assert_valid();
+
+        self.metadata_limits = limits;
+    }
+}
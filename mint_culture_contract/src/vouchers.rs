@@ -0,0 +1,85 @@
+use crate::*;
+use crate::internal::{assert_one_yocto, charge_price_and_storage};
+use ed25519_dalek::Verifier;
+use near_sdk::CurveType;
+
+// 백엔드가 서명한 지연 민팅 바우처
+//
+// 서명 대상 메시지: borsh((컨트랙트 계정 ID, 바우처)). 컨트랙트 ID 를 포함하므로 다른 컨트랙트에서 재사용할 수 없음
+#[derive(BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
+#[serde(crate = "near_sdk::serde")]
+pub struct MintVoucher {
+    pub token_id: TokenId,
+    pub metadata: TokenMetadata,
+    pub receiver_id: AccountId,
+    // 바우처를 사용할 때 내야 하는 금액 (yoctoNEAR). 컨트랙트 소유자에게 전송됨
+    pub price: U128,
+    pub expires_at: U64,
+    // 한 번 사용된 nonce 는 다시 사용할 수 없음
+    pub nonce: U64,
+}
+
+impl MintVoucher {
+    pub(crate) fn signing_message(&self) -> Vec<u8> {
+        (env::current_account_id(), self).try_to_vec().unwrap()
+    }
+}
+
+#[near_bindgen]
+impl Contract {
+    // 바우처 서명 키 등록 또는 교체 (소유자만 가능). 이전 키로 서명된 바우처는 더 이상 사용할 수 없음
+    #[payable]
+    pub fn set_voucher_signer(&mut self, public_key: PublicKey) {
+        assert_one_yocto();
+        self.assert_owner();
+        assert!(
+            matches!(public_key.curve_type(), CurveType::ED25519),
+            "The voucher signer must be an ed25519 key"
+        );
+        self.voucher_signer = Some(public_key);
+    }
+
+    pub fn get_voucher_signer(&self) -> Option<PublicKey> {
+        self.voucher_signer.clone()
+    }
+
+    pub fn is_voucher_nonce_used(&self, nonce: U64) -> bool {
+        self.used_voucher_nonces.contains(&nonce.0)
+    }
+
+    // 서명된 바우처로 토큰을 민팅합니다. 누구나 호출할 수 있으며 가격과 스토리지 비용을 첨부해야 합니다.
+    #[payable]
+    pub fn nft_redeem_voucher(&mut self, voucher: MintVoucher, signature: Base64VecU8) {
+        let signer = self.voucher_signer.clone().expect("No voucher signer is registered");
+        assert!(
+            verify_ed25519(&signer, &voucher.signing_message(), &signature.0),
+            "Invalid voucher signature"
+        );
+        assert!(env::block_timestamp() < voucher.expires_at.0, "The voucher has expired");
+
+        let initial_storage_usage = env::storage_usage();
+        assert!(
+            self.used_voucher_nonces.insert(&voucher.nonce.0),
+            "Voucher nonce {} has already been used",
+            voucher.nonce.0
+        );
+
+        let price = voucher.price.0;
+        self.internal_mint_with_price(voucher.token_id, voucher.metadata, voucher.receiver_id, None, Some(voucher.price));
+
+        charge_price_and_storage(env::storage_usage() - initial_storage_usage, price, self.owner_id.clone());
+    }
+}
+
+pub(crate) fn verify_ed25519(public_key: &PublicKey, message: &[u8], signature: &[u8]) -> bool {
+    // PublicKey 의 첫 바이트는 곡선 종류
+    let public_key = match ed25519_dalek::PublicKey::from_bytes(&public_key.as_bytes()[1..]) {
+        Ok(public_key) => public_key,
+        Err(_) => return false,
+    };
+    let signature = match ed25519_dalek::Signature::try_from(signature) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
+    public_key.verify(message, &signature).is_ok()
+}